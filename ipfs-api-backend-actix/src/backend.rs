@@ -14,11 +14,11 @@ use futures::{FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use http::{
     header::{HeaderName, HeaderValue},
     uri::Scheme,
-    StatusCode, Uri,
+    Method, StatusCode, Uri,
 };
-use ipfs_api_prelude::{ApiRequest, Backend, BoxStream, TryFromUri};
+use ipfs_api_prelude::{ApiRequest, Backend, BoxStream, RetryPolicy, TryFromUri};
 use multipart::client::multipart;
-use std::time::Duration;
+use std::{sync::OnceLock, time::Duration};
 
 const ACTIX_REQUEST_TIMEOUT: Duration = Duration::from_secs(90);
 
@@ -28,6 +28,11 @@ pub struct ActixBackend {
 
     /// Username and password
     credentials: Option<(String, String)>,
+
+    retry: RetryPolicy,
+
+    /// Whether the daemon serves the routing API
+    routing_api: OnceLock<bool>,
 }
 
 impl Default for ActixBackend {
@@ -48,6 +53,8 @@ impl TryFromUri for ActixBackend {
             base,
             client,
             credentials: None,
+            retry: RetryPolicy::none(),
+            routing_api: OnceLock::new(),
         }
     }
 }
@@ -59,11 +66,52 @@ impl ActixBackend {
         P: Into<String>,
     {
         Self {
-            base: self.base,
-            client: self.client,
             credentials: Some((username.into(), password.into())),
+            ..self
+        }
+    }
+
+    /// Retry idempotent requests that fail with a transient error, according
+    /// to the given policy.
+    ///
+    pub fn with_retry_policy(self, retry: RetryPolicy) -> Self {
+        Self { retry, ..self }
+    }
+
+    fn build_request(
+        &self,
+        method: Method,
+        url: Uri,
+        form: Option<multipart::Form<'static>>,
+    ) -> awc::SendClientRequest {
+        let req = self.client.request(method, url);
+        let req = if let Some((username, password)) = &self.credentials {
+            req.basic_auth(username, password)
+        } else {
+            req
+        };
+
+        if let Some(form) = form {
+            req.content_type(form.content_type())
+                .send_body(multipart::Body::from(form))
+        } else {
+            req.timeout(ACTIX_REQUEST_TIMEOUT).send()
         }
     }
+
+    async fn send_raw(
+        &self,
+        method: Method,
+        url: Uri,
+        form: Option<multipart::Form<'static>>,
+    ) -> Result<(StatusCode, Bytes), Error> {
+        let mut res = self.build_request(method, url, form).await?;
+        let status = res.status();
+        let body = res.body().await?;
+
+        // FIXME: Actix compat with bytes 1.0
+        Ok((status, body))
+    }
 }
 
 #[async_trait(?Send)]
@@ -82,6 +130,10 @@ impl Backend for ActixBackend {
         (self as ActixBackend).with_credentials(username, password)
     }
 
+    fn routing_api_cache(&self) -> Option<&OnceLock<bool>> {
+        Some(&self.routing_api)
+    }
+
     fn build_base_request<Req>(
         &self,
         req: Req,
@@ -91,20 +143,8 @@ impl Backend for ActixBackend {
         Req: ApiRequest,
     {
         let url = req.absolute_url(&self.base)?;
-        let req = self.client.request(Req::METHOD, url);
-        let req = if let Some((username, password)) = &self.credentials {
-            req.basic_auth(username, password)
-        } else {
-            req
-        };
-        let req = if let Some(form) = form {
-            req.content_type(form.content_type())
-                .send_body(multipart::Body::from(form))
-        } else {
-            req.timeout(ACTIX_REQUEST_TIMEOUT).send()
-        };
 
-        Ok(req)
+        Ok(self.build_request(Req::METHOD, url, form))
     }
 
     fn get_header(res: &Self::HttpResponse, key: HeaderName) -> Option<&HeaderValue> {
@@ -119,13 +159,21 @@ impl Backend for ActixBackend {
     where
         Req: ApiRequest,
     {
-        let req = self.build_base_request(req, form)?;
-        let mut res = req.await?;
-        let status = res.status();
-        let body = res.body().await?;
+        let url = req.absolute_url(&self.base)?;
 
-        // FIXME: Actix compat with bytes 1.0
-        Ok((status, body))
+        // A multipart body is consumed when it is sent, so it can't be
+        // sent again.
+        //
+        if !Req::IDEMPOTENT || form.is_some() {
+            return self.send_raw(Req::METHOD, url, form).await;
+        }
+
+        self.retry
+            .run(
+                || self.send_raw(Req::METHOD, url.clone(), None),
+                Error::class,
+            )
+            .await
     }
 
     fn response_to_byte_stream(res: Self::HttpResponse) -> BoxStream<Bytes, Self::Error> {
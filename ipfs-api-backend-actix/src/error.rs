@@ -6,6 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use awc::error::{PayloadError, SendRequestError};
+use ipfs_api_prelude::ErrorClass;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -31,3 +33,18 @@ impl From<ipfs_api_prelude::ApiError> for Error {
         Error::Api(err)
     }
 }
+
+impl Error {
+    /// Classifies the error, to decide whether a request can be retried.
+    ///
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            Error::ClientSend(SendRequestError::Timeout) => ErrorClass::Timeout,
+            Error::ClientSend(SendRequestError::Connect(_) | SendRequestError::Send(_))
+            | Error::ClientPayload(PayloadError::Incomplete(_) | PayloadError::Io(_)) => {
+                ErrorClass::Connection
+            }
+            _ => ErrorClass::Other,
+        }
+    }
+}
@@ -28,7 +28,10 @@ mod error;
 pub type IpfsClient = ActixBackend;
 pub use crate::{backend::ActixBackend, error::Error};
 pub use ipfs_api_prelude::{
-    request::{self, KeyType, Logger, LoggingLevel, ObjectTemplate},
-    response, ApiError, BackendWithGlobalOptions, GlobalOptions, IpfsApi, TryFromUri,
+    config, ids,
+    request::{self, KeyFormat, KeyType, Logger, LoggingLevel, ObjectTemplate},
+    response, AddPathFilter, ApiError, BackendWithGlobalOptions, CatReader, ErrorClass,
+    GetProgress, GlobalOptions, IpfsApi, ResilientSubscription, RetryPolicy, SubscriptionEvent,
+    SymlinkPolicy, TryFromUri,
 };
 pub use multipart::client::multipart::Form;
@@ -13,14 +13,15 @@ use futures::{FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use http::{
     header::{HeaderName, HeaderValue},
     uri::Scheme,
-    StatusCode, Uri,
+    Method, StatusCode, Uri,
 };
 use hyper::{
     body,
     client::{self, connect::Connect, Builder, HttpConnector},
 };
-use ipfs_api_prelude::{ApiRequest, Backend, BoxStream, TryFromUri};
+use ipfs_api_prelude::{ApiRequest, Backend, BoxStream, RetryPolicy, TryFromUri};
 use multipart::client::multipart;
+use std::sync::OnceLock;
 
 macro_rules! impl_default {
     ($http_connector:path) => {
@@ -37,6 +38,11 @@ macro_rules! impl_default {
 
             /// Username and password
             credentials: Option<(String, String)>,
+
+            retry: RetryPolicy,
+
+            /// Whether the daemon serves the routing API
+            routing_api: OnceLock<bool>,
         }
 
         impl Default for HyperBackend<$http_connector> {
@@ -58,6 +64,8 @@ macro_rules! impl_default {
                     base,
                     client,
                     credentials: None,
+                    retry: RetryPolicy::none(),
+                    routing_api: OnceLock::new(),
                 }
             }
         }
@@ -95,12 +103,18 @@ impl<C: Connect + Clone + Send + Sync + 'static> HyperBackend<C> {
         P: Into<String>,
     {
         Self {
-            base: self.base,
-            client: self.client,
             credentials: Some((username.into(), password.into())),
+            ..self
         }
     }
 
+    /// Retry idempotent requests that fail with a transient error, according
+    /// to the given policy.
+    ///
+    pub fn with_retry_policy(self, retry: RetryPolicy) -> Self {
+        Self { retry, ..self }
+    }
+
     fn basic_authorization(&self) -> Option<String> {
         self.credentials.as_ref().map(|(username, password)| {
             let credentials = format!("{}:{}", username, password);
@@ -109,6 +123,44 @@ impl<C: Connect + Clone + Send + Sync + 'static> HyperBackend<C> {
             format!("Basic {}", encoded)
         })
     }
+
+    fn build_request(
+        &self,
+        method: Method,
+        url: Uri,
+        form: Option<multipart::Form<'static>>,
+    ) -> Result<http::Request<hyper::Body>, Error> {
+        let builder = http::Request::builder();
+        let builder = builder.method(method).uri(url);
+
+        let builder = if let Some(authorization) = self.basic_authorization() {
+            builder.header(hyper::header::AUTHORIZATION, authorization)
+        } else {
+            builder
+        };
+
+        let req = if let Some(form) = form {
+            form.set_body_convert::<hyper::Body, multipart::Body>(builder)
+        } else {
+            builder.body(hyper::Body::empty())
+        }?;
+
+        Ok(req)
+    }
+
+    async fn send_raw(
+        &self,
+        method: Method,
+        url: Uri,
+        form: Option<multipart::Form<'static>>,
+    ) -> Result<(StatusCode, Bytes), Error> {
+        let req = self.build_request(method, url, form)?;
+        let res = self.client.request(req).await?;
+        let status = res.status();
+        let body = body::to_bytes(res.into_body()).await?;
+
+        Ok((status, body))
+    }
 }
 
 #[cfg_attr(feature = "with-send-sync", async_trait)]
@@ -131,6 +183,10 @@ where
         (self as HyperBackend<C>).with_credentials(username, password)
     }
 
+    fn routing_api_cache(&self) -> Option<&OnceLock<bool>> {
+        Some(&self.routing_api)
+    }
+
     fn build_base_request<Req>(
         &self,
         req: Req,
@@ -141,22 +197,7 @@ where
     {
         let url = req.absolute_url(&self.base)?;
 
-        let builder = http::Request::builder();
-        let builder = builder.method(Req::METHOD).uri(url);
-
-        let builder = if let Some(authorization) = self.basic_authorization() {
-            builder.header(hyper::header::AUTHORIZATION, authorization)
-        } else {
-            builder
-        };
-
-        let req = if let Some(form) = form {
-            form.set_body_convert::<hyper::Body, multipart::Body>(builder)
-        } else {
-            builder.body(hyper::Body::empty())
-        }?;
-
-        Ok(req)
+        self.build_request(Req::METHOD, url, form)
     }
 
     fn get_header(res: &Self::HttpResponse, key: HeaderName) -> Option<&HeaderValue> {
@@ -171,12 +212,21 @@ where
     where
         Req: ApiRequest,
     {
-        let req = self.build_base_request(req, form)?;
-        let res = self.client.request(req).await?;
-        let status = res.status();
-        let body = body::to_bytes(res.into_body()).await?;
+        let url = req.absolute_url(&self.base)?;
 
-        Ok((status, body))
+        // A multipart body is consumed when it is sent, so it can't be
+        // sent again.
+        //
+        if !Req::IDEMPOTENT || form.is_some() {
+            return self.send_raw(Req::METHOD, url, form).await;
+        }
+
+        self.retry
+            .run(
+                || self.send_raw(Req::METHOD, url.clone(), None),
+                Error::class,
+            )
+            .await
     }
 
     fn response_to_byte_stream(res: Self::HttpResponse) -> BoxStream<Bytes, Self::Error> {
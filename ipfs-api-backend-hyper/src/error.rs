@@ -6,6 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use ipfs_api_prelude::ErrorClass;
+use std::{error::Error as _, io};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -28,3 +30,34 @@ impl From<ipfs_api_prelude::ApiError> for Error {
         Error::Api(err)
     }
 }
+
+impl Error {
+    /// Classifies the error, to decide whether a request can be retried.
+    ///
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            Error::Client(e) if e.is_timeout() => ErrorClass::Timeout,
+            Error::Client(e) if is_connection_error(e) => ErrorClass::Connection,
+            _ => ErrorClass::Other,
+        }
+    }
+}
+
+fn is_connection_error(e: &hyper::Error) -> bool {
+    if e.is_connect() || e.is_closed() || e.is_canceled() || e.is_incomplete_message() {
+        return true;
+    }
+
+    // Connection resets surface as io errors, while reading or writing.
+    //
+    matches!(
+        e.source()
+            .and_then(|e| e.downcast_ref::<io::Error>())
+            .map(io::Error::kind),
+        Some(
+            io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::BrokenPipe
+        )
+    )
+}
@@ -54,7 +54,7 @@ async fn main() {
     // Read tar file from IPFS.
     //
     match client
-        .tar_cat(&file.hash[..])
+        .tar_cat(&file.hash.to_string())
         .map_ok(|chunk| chunk.to_vec())
         .try_concat()
         .await
@@ -34,7 +34,7 @@ async fn main() -> Result<(), &'static str> {
     eprintln!("cat {} bytes from {}...", length, offset);
 
     let section = client
-        .cat_range(&added_file.hash[..], offset, length)
+        .cat_range(&added_file.hash.to_string(), offset, length)
         .map_ok(|chunk| chunk.to_vec())
         .try_concat()
         .await
@@ -46,7 +46,7 @@ async fn main() -> Result<(), &'static str> {
     eprintln!("reading full file...");
 
     let all = client
-        .cat(&added_file.hash[..])
+        .cat(&added_file.hash.to_string())
         .map_ok(|chunk| chunk.to_vec())
         .try_concat()
         .await
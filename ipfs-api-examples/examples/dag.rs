@@ -28,7 +28,7 @@ async fn main() {
         .await
         .expect("error adding dag node");
 
-    let cid = response.cid.cid_string;
+    let cid = response.cid.cid_string.to_string();
 
     match client
         .dag_get(&cid)
@@ -37,7 +37,7 @@ async fn main() {
                 let streams: Vec<&str> = peer.streams.iter().map(|s| &s.protocol[..]).collect();
                 eprintln!("  addr:     {}", peer.addr);
                 eprintln!("  peer:     {}", peer.peer);
-                eprintln!("  latency:  {}", peer.latency);
+                eprintln!("  latency:  {:?}", peer.latency);
                 eprintln!("  muxer:    {}", peer.muxer);
                 eprintln!("  streams:  {}", streams.join(", "));
                 eprintln!();
@@ -41,7 +41,7 @@ async fn main() {
     eprintln!("streaming 10 pings...");
 
     if let Err(e) = client
-        .ping(&peer.peer[..], Some(10))
+        .ping(&peer.peer.to_string(), Some(10))
         .try_for_each(|ping| {
             eprintln!("{:?}", ping);
 
@@ -56,7 +56,7 @@ async fn main() {
     eprintln!("gathering 15 pings...");
 
     match client
-        .ping(&peer.peer[..], Some(15))
+        .ping(&peer.peer.to_string(), Some(15))
         .try_collect::<Vec<PingResponse>>()
         .await
     {
@@ -0,0 +1,288 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use crate::request;
+use common_multipart_rfc7578::client::multipart;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use mime::Mime;
+use std::{
+    fs::{self, File, Metadata},
+    io::{self, Cursor, Read},
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+/// How symbolic links are handled when adding a directory.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Symbolic links are not added.
+    #[default]
+    Skip,
+
+    /// Symbolic links are followed, and the files and directories they point
+    /// to are added in their place.
+    Follow,
+
+    /// Symbolic links are added as IPFS symlinks to their target.
+    Preserve,
+}
+
+/// Controls which files are added when adding a directory with
+/// `add_path_with_options`.
+///
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AddPathFilter<'a> {
+    /// Gitignore-style patterns for files and directories to leave out.
+    /// Patterns are matched relative to the path being added.
+    #[cfg_attr(feature = "with-builder", builder(default))]
+    pub exclude: &'a [&'a str],
+
+    /// Leave out files and directories whose names start with a `.`.
+    #[cfg_attr(feature = "with-builder", builder(default))]
+    pub exclude_hidden: bool,
+
+    /// How symbolic links are handled.
+    #[cfg_attr(feature = "with-builder", builder(default))]
+    pub symlinks: SymlinkPolicy,
+}
+
+impl<'a> AddPathFilter<'a> {
+    fn matcher(&self, root: &Path) -> Result<Gitignore, crate::Error> {
+        let mut builder = GitignoreBuilder::new(root);
+
+        for pattern in self.exclude {
+            builder
+                .add_line(None, pattern)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        }
+
+        builder
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e).into())
+    }
+}
+
+/// A file that isn't opened until the multipart body reaches it, and is
+/// closed as soon as it has been read to the end.
+///
+struct LazyFile {
+    path: PathBuf,
+    file: Option<File>,
+    done: bool,
+}
+
+impl LazyFile {
+    fn new(path: PathBuf) -> LazyFile {
+        LazyFile {
+            path,
+            file: None,
+            done: false,
+        }
+    }
+}
+
+impl Read for LazyFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.done {
+            return Ok(0);
+        }
+
+        let file = match self.file {
+            Some(ref mut file) => file,
+            None => self.file.insert(File::open(&self.path)?),
+        };
+        let read = file.read(buf)?;
+
+        if read == 0 && !buf.is_empty() {
+            self.file = None;
+            self.done = true;
+        }
+
+        Ok(read)
+    }
+}
+
+/// Returns the content type of a file's part. If they are to be preserved,
+/// its mode and modification time are sent as parameters of the content
+/// type, which is where go-ipfs-files puts them.
+///
+fn content_type(essence: &str, metadata: &Metadata, add: &request::Add) -> Mime {
+    let mut content_type = essence.to_string();
+
+    #[cfg(unix)]
+    if add.preserve_mode == Some(true) {
+        use std::os::unix::fs::PermissionsExt;
+
+        content_type.push_str(&format!(
+            "; mode={:04o}",
+            metadata.permissions().mode() & 0o7777
+        ));
+    }
+
+    if add.preserve_mtime == Some(true) {
+        if let Ok(Ok(mtime)) = metadata.modified().map(|t| t.duration_since(UNIX_EPOCH)) {
+            content_type.push_str(&format!("; mtime={}", mtime.as_secs()));
+
+            if mtime.subsec_nanos() > 0 {
+                content_type.push_str(&format!("; mtime-nsecs={}", mtime.subsec_nanos()));
+            }
+        }
+    }
+
+    content_type.parse().unwrap()
+}
+
+/// Builds the multipart form for adding a file or directory.
+///
+/// Only the directory listing is read up front. Files are opened one at a
+/// time as the request body is sent.
+///
+pub(crate) fn add_path_form(
+    path: &Path,
+    filter: &AddPathFilter,
+    add: &request::Add,
+) -> Result<multipart::Form<'static>, crate::Error> {
+    let prefix = path.parent();
+    let matcher = filter.matcher(path)?;
+    let mut form = multipart::Form::default();
+
+    let walker = walkdir::WalkDir::new(path)
+        .follow_links(filter.symlinks == SymlinkPolicy::Follow)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 {
+                return true;
+            }
+
+            if filter.exclude_hidden && entry.file_name().to_string_lossy().starts_with('.') {
+                return false;
+            }
+
+            !matcher
+                .matched(entry.path(), entry.file_type().is_dir())
+                .is_ignore()
+        });
+
+    for entry in walker {
+        let entry = entry.map_err(|e| crate::Error::Io(e.into()))?;
+        let file_name = match prefix {
+            Some(prefix) => entry.path().strip_prefix(prefix).unwrap(),
+            None => entry.path(),
+        }
+        .to_string_lossy()
+        .into_owned();
+
+        if entry.file_type().is_file() {
+            let content_type = content_type(
+                "application/octet-stream",
+                &entry.metadata().map_err(|e| crate::Error::Io(e.into()))?,
+                add,
+            );
+            let file = LazyFile::new(entry.into_path());
+
+            form.add_reader_file_with_mime("path", file, file_name, content_type);
+        } else if entry.file_type().is_symlink() && filter.symlinks == SymlinkPolicy::Preserve {
+            let content_type = content_type(
+                "application/symlink",
+                &entry.metadata().map_err(|e| crate::Error::Io(e.into()))?,
+                add,
+            );
+            let target = fs::read_link(entry.path())?;
+            let target = target.to_string_lossy().into_owned();
+
+            form.add_reader_file_with_mime("path", Cursor::new(target), file_name, content_type);
+        }
+    }
+
+    Ok(form)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_lazy_file_opens_on_first_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        let mut file = LazyFile::new(path.clone());
+
+        assert!(file.file.is_none());
+
+        File::create(&path).unwrap().write_all(b"hello").unwrap();
+
+        let mut buf = String::new();
+        file.read_to_string(&mut buf).unwrap();
+
+        assert_eq!(buf, "hello");
+        assert!(file.file.is_none());
+    }
+
+    #[test]
+    fn test_content_type() {
+        let dir = tempfile::tempdir().unwrap();
+        let metadata = fs::metadata(dir.path()).unwrap();
+        let add = request::Add {
+            preserve_mtime: Some(true),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            content_type("application/octet-stream", &metadata, &Default::default()),
+            mime::APPLICATION_OCTET_STREAM
+        );
+
+        let with_mtime = content_type("application/octet-stream", &metadata, &add);
+
+        assert_eq!(with_mtime.essence_str(), "application/octet-stream");
+        assert!(with_mtime.get_param("mtime").is_some());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_form_sends_mode() {
+        use futures::TryStreamExt;
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("run.sh");
+        File::create(&path).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let add = request::Add {
+            preserve_mode: Some(true),
+            ..Default::default()
+        };
+        let form = add_path_form(&path, &Default::default(), &add).unwrap();
+        let body = multipart::Body::from(form).try_concat().await.unwrap();
+        let body = String::from_utf8_lossy(&body);
+
+        assert!(body.contains("content-type: application/octet-stream; mode=0755\r\n"));
+        assert!(body.contains("content-disposition: form-data; name=\"path\"; filename=\"run.sh\""));
+    }
+
+    #[test]
+    fn test_filter_excludes() {
+        let exclude = ["target/", "*.log", "!keep.log"];
+        let filter = AddPathFilter {
+            exclude: &exclude,
+            ..Default::default()
+        };
+        let matcher = filter.matcher(Path::new("./root")).unwrap();
+
+        assert!(matcher.matched("./root/target", true).is_ignore());
+        assert!(!matcher.matched("./root/target", false).is_ignore());
+        assert!(matcher.matched("./root/src/out.log", false).is_ignore());
+        assert!(!matcher.matched("./root/keep.log", false).is_ignore());
+        assert!(!matcher.matched("./root/src/lib.rs", false).is_ignore());
+    }
+}
@@ -6,19 +6,22 @@
 // copied, modified, or distributed except according to those terms.
 //
 
-use crate::{read::LineDecoder, request, response, Backend, BoxStream};
+use crate::{
+    add_path::add_path_form, config, get_path::GetWriter, read::LineDecoder, request, response,
+    AddPathFilter, Backend, BoxStream, CatReader, GetProgress, ResilientSubscription,
+};
 use async_trait::async_trait;
 use bytes::Bytes;
 use common_multipart_rfc7578::client::multipart;
-use futures::{future, AsyncRead, FutureExt, TryStreamExt};
+use futures::{future, stream, AsyncRead, FutureExt, TryStreamExt};
+use serde::{de::DeserializeOwned, Serialize};
 use std::{
-    fs::File,
+    fmt::Display,
     io::{Cursor, Read},
-    path::{Path, PathBuf},
+    path::Path,
+    time::Duration,
 };
 
-const FILE_DESCRIPTOR_LIMIT: usize = 127;
-
 // Implements a call to the IPFS that returns a streaming body response.
 // Implementing this in a macro is necessary because the Rust compiler
 // can't reason about the lifetime of the request instance properly. It
@@ -122,6 +125,11 @@ pub trait IpfsApi: Backend {
 
         form.add_reader("path", data);
 
+        let add = request::Add {
+            progress: None,
+            ..add
+        };
+
         self.request(add, Some(form)).await
     }
 
@@ -162,9 +170,62 @@ pub trait IpfsApi: Backend {
 
         form.add_async_reader("path", data);
 
+        let add = request::Add {
+            progress: None,
+            ..add
+        };
+
         self.request(add, Some(form)).await
     }
 
+    /// Add a file to IPFS with options, streaming progress events as the
+    /// file is read, followed by the added file.
+    ///
+    /// The `progress` option is always set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{response::AddEvent, IpfsApi, IpfsClient};
+    /// use std::fs::File;
+    ///
+    /// let client = IpfsClient::default();
+    /// let data = File::open("large.bin").unwrap();
+    /// let res = client
+    ///     .add_stream(data, Default::default())
+    ///     .try_for_each(|event| {
+    ///         match event {
+    ///             AddEvent::Progress { name, bytes } => eprintln!("{}: {} bytes", name, bytes),
+    ///             AddEvent::Added(added) => eprintln!("added {}", added.hash),
+    ///         }
+    ///
+    ///         futures::future::ok(())
+    ///     });
+    /// ```
+    ///
+    fn add_stream<R>(
+        &self,
+        data: R,
+        add: request::Add,
+    ) -> BoxStream<response::AddEvent, Self::Error>
+    where
+        R: 'static + Read + Send + Sync + Unpin,
+    {
+        let mut form = multipart::Form::default();
+
+        form.add_reader("path", data);
+
+        let add = request::Add {
+            progress: Some(true),
+            ..add
+        };
+
+        impl_stream_api_response! {
+            (self, add, Some(form)) => request_stream_json
+        }
+    }
+
     /// Add files using multipart::Form
     ///
     /// # Examples
@@ -181,6 +242,7 @@ pub trait IpfsApi: Backend {
     /// #[cfg(not(feature = "with-builder"))]
     /// let add = ipfs_api::request::Add {
     ///     wrap_with_directory: Some(true),
+    ///     preserve_mode: Some(true),
     ///     ..Default::default()
     /// };
     ///
@@ -203,8 +265,7 @@ pub trait IpfsApi: Backend {
     }
 
     /// Add a path to Ipfs. Can be a file or directory.
-    /// A hard limit of 128 open file descriptors is set such
-    /// that any small additional files are stored in-memory.
+    /// Files are opened one at a time as the request is sent.
     ///
     /// # Examples
     ///
@@ -220,52 +281,108 @@ pub trait IpfsApi: Backend {
     where
         P: AsRef<Path> + Send,
     {
-        let prefix = path.as_ref().parent();
-        let mut paths_to_add: Vec<(PathBuf, u64)> = vec![];
-
-        for path in walkdir::WalkDir::new(path.as_ref()) {
-            match path {
-                Ok(entry) if entry.file_type().is_file() => {
-                    let file_size = entry
-                        .metadata()
-                        .map(|metadata| metadata.len())
-                        .map_err(|e| crate::Error::Io(e.into()))?;
-
-                    paths_to_add.push((entry.path().to_path_buf(), file_size));
-                }
-                Ok(_) => (),
-                Err(e) => return Err(crate::Error::Io(e.into()).into()),
-            }
-        }
-
-        paths_to_add.sort_unstable_by(|(_, a), (_, b)| a.cmp(b).reverse());
+        self.add_path_with_options(path, request::Add::default(), AddPathFilter::default())
+            .await
+    }
 
-        let mut it = 0;
-        let mut form = multipart::Form::default();
+    /// Add a path to Ipfs with options. Files and directories that don't
+    /// pass the filter are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ipfs_api::{AddPathFilter, IpfsApi, IpfsClient, SymlinkPolicy};
+    ///
+    /// let client = IpfsClient::default();
+    /// let exclude = ["target/", "*.log"];
+    /// #[cfg(feature = "with-builder")]
+    /// let filter = AddPathFilter::builder()
+    ///     .exclude(&exclude)
+    ///     .exclude_hidden(true)
+    ///     .symlinks(SymlinkPolicy::Preserve)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let filter = AddPathFilter {
+    ///     exclude: &exclude,
+    ///     exclude_hidden: true,
+    ///     symlinks: SymlinkPolicy::Preserve,
+    /// };
+    /// let add = ipfs_api::request::Add {
+    ///     wrap_with_directory: Some(true),
+    ///     preserve_mode: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let res = client.add_path_with_options("./src", add, filter);
+    /// ```
+    ///
+    async fn add_path_with_options<P>(
+        &self,
+        path: P,
+        add: request::Add<'_>,
+        filter: AddPathFilter<'_>,
+    ) -> Result<Vec<response::AddResponse>, Self::Error>
+    where
+        P: AsRef<Path> + Send,
+    {
+        let form = add_path_form(path.as_ref(), &filter, &add)?;
+        let add = request::Add {
+            progress: None,
+            ..add
+        };
+        let req = self.build_base_request(add, Some(form))?;
 
-        for (path, file_size) in paths_to_add {
-            let mut file = File::open(&path).map_err(crate::Error::Io)?;
-            let file_name = match prefix {
-                Some(prefix) => path.strip_prefix(prefix).unwrap(),
-                None => path.as_path(),
-            }
-            .to_string_lossy();
+        self.request_stream_json(req).try_collect().await
+    }
 
-            if it < FILE_DESCRIPTOR_LIMIT {
-                form.add_reader_file("path", file, file_name);
+    /// Add a path to Ipfs with options, streaming progress events as files
+    /// are read, followed by each added file and directory. Files and
+    /// directories that don't pass the filter are left out.
+    ///
+    /// The `progress` option is always set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{response::AddEvent, AddPathFilter, IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let exclude = ["target/"];
+    /// let filter = AddPathFilter {
+    ///     exclude: &exclude,
+    ///     ..Default::default()
+    /// };
+    /// let res = client
+    ///     .add_path_stream("./src", Default::default(), filter)
+    ///     .try_filter_map(|event| match event {
+    ///         AddEvent::Added(added) => futures::future::ok(Some(added)),
+    ///         AddEvent::Progress { .. } => futures::future::ok(None),
+    ///     })
+    ///     .try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn add_path_stream<P>(
+        &self,
+        path: P,
+        add: request::Add,
+        filter: AddPathFilter,
+    ) -> BoxStream<response::AddEvent, Self::Error>
+    where
+        P: AsRef<Path>,
+    {
+        let form = match add_path_form(path.as_ref(), &filter, &add) {
+            Ok(form) => form,
+            Err(e) => return Box::new(future::err(e.into()).into_stream()),
+        };
 
-                it += 1;
-            } else {
-                let mut buf = Vec::with_capacity(file_size as usize);
-                let _ = file.read_to_end(&mut buf).map_err(crate::Error::Io)?;
+        let add = request::Add {
+            progress: Some(true),
+            ..add
+        };
 
-                form.add_reader_file("path", Cursor::new(buf), file_name);
-            }
+        impl_stream_api_response! {
+            (self, add, Some(form)) => request_stream_json
         }
-
-        let req = self.build_base_request(request::Add::default(), Some(form))?;
-
-        self.request_stream_json(req).try_collect().await
     }
 
     /// Returns the current ledger for a peer.
@@ -371,7 +488,10 @@ pub trait IpfsApi: Backend {
     ///     .try_concat();
     /// ```
     ///
-    fn block_get(&self, hash: &str) -> BoxStream<Bytes, Self::Error> {
+    fn block_get<H>(&self, hash: &H) -> BoxStream<Bytes, Self::Error>
+    where
+        H: ?Sized + Display + Sync,
+    {
         impl_stream_api_response! {
             (self, request::BlockGet { hash }, None) => request_stream_bytes
         }
@@ -463,7 +583,10 @@ pub trait IpfsApi: Backend {
     /// let res = client.block_stat("QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA");
     /// ```
     ///
-    async fn block_stat(&self, hash: &str) -> Result<response::BlockStatResponse, Self::Error> {
+    async fn block_stat<H>(&self, hash: &H) -> Result<response::BlockStatResponse, Self::Error>
+    where
+        H: ?Sized + Display + Sync,
+    {
         self.request(request::BlockStat { hash }, None).await
     }
 
@@ -531,11 +654,10 @@ pub trait IpfsApi: Backend {
     /// ```
     ///
     fn cat(&self, path: &str) -> BoxStream<Bytes, Self::Error> {
-        let offset = None;
-        let length = None;
-        impl_stream_api_response! {
-            (self, request::Cat { path, offset, length }, None) => request_stream_bytes
-        }
+        self.cat_with_options(request::Cat {
+            path,
+            ..Default::default()
+        })
     }
 
     /// Returns the the specified range of bytes of an Ipfs object.
@@ -564,13 +686,73 @@ pub trait IpfsApi: Backend {
         _offset: usize,
         _length: usize,
     ) -> BoxStream<Bytes, Self::Error> {
-        let offset = Some(_offset);
-        let length = Some(_length);
+        self.cat_with_options(request::Cat {
+            path,
+            offset: Some(_offset as u64),
+            length: Some(_length as u64),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the contents of an Ipfs object with options.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let hash = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::Cat::builder()
+    ///     .path(hash)
+    ///     .offset(5_000_000_000)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::Cat {
+    ///     path: hash,
+    ///     offset: Some(5_000_000_000),
+    ///     ..Default::default()
+    /// };
+    /// let res = client
+    ///     .cat_with_options(req)
+    ///     .map_ok(|chunk| chunk.to_vec())
+    ///     .try_concat();
+    /// ```
+    ///
+    fn cat_with_options(&self, options: request::Cat) -> BoxStream<Bytes, Self::Error> {
         impl_stream_api_response! {
-            (self, request::Cat { path, offset, length }, None) => request_stream_bytes
+            (self, options, None) => request_stream_bytes
         }
     }
 
+    /// Returns a reader over the contents of an Ipfs object that implements
+    /// `AsyncRead` and `AsyncSeek`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::{AsyncReadExt, AsyncSeekExt};
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::io::SeekFrom;
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let client = IpfsClient::default();
+    /// let hash = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// let mut reader = client.cat_reader(hash);
+    /// let mut buf = vec![0; 1024];
+    ///
+    /// reader.seek(SeekFrom::End(-1024)).await?;
+    /// reader.read_exact(&mut buf).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    fn cat_reader(&self, path: &str) -> CatReader<'_, Self> {
+        CatReader::new(self, path)
+    }
+
     /// List available commands that the server accepts.
     ///
     /// ```no_run
@@ -728,6 +910,75 @@ pub trait IpfsApi: Backend {
         .await
     }
 
+    /// Get an ipfs config value, deserialized into `T`.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// # async fn run() -> Result<(), ipfs_api::Error> {
+    /// let client = IpfsClient::default();
+    /// let hash_on_read: bool = client.config_get("Datastore.HashOnRead").await?;
+    /// let swarm: Vec<String> = client.config_get("Addresses.Swarm").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    async fn config_get<T>(&self, key: &str) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let res = self.config_get_json(key).await?;
+
+        Ok(serde_json::from_value(res.value).map_err(crate::Error::Parse)?)
+    }
+
+    /// Set an ipfs config value. Strings are sent as is, booleans with the
+    /// `bool` flag, and anything else as json.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.config_set("Swarm.ConnMgr.HighWater", &200);
+    /// ```
+    ///
+    async fn config_set<T>(
+        &self,
+        key: &str,
+        value: &T,
+    ) -> Result<response::ConfigResponse, Self::Error>
+    where
+        T: ?Sized + Serialize + Sync,
+    {
+        match serde_json::to_value(value).map_err(crate::Error::Parse)? {
+            serde_json::Value::String(value) => self.config_set_string(key, &value).await,
+            serde_json::Value::Bool(value) => self.config_set_bool(key, value).await,
+            value => self.config_set_json(key, &value.to_string()).await,
+        }
+    }
+
+    /// Apply a config profile, such as `server`, `lowpower` or `badgerds`.
+    ///
+    /// Returns the config before and after the profile is applied. With
+    /// `dry_run`, the profile is not applied, so the changes can be
+    /// reviewed first.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.config_profile_apply("lowpower", true);
+    /// ```
+    ///
+    async fn config_profile_apply(
+        &self,
+        profile: &str,
+        dry_run: bool,
+    ) -> Result<response::ConfigProfileApplyResponse, Self::Error> {
+        self.request(request::ConfigProfileApply { profile, dry_run }, None)
+            .await
+    }
+
     /// Opens the config file for editing (on the server).
     ///
     /// ```no_run
@@ -781,6 +1032,63 @@ pub trait IpfsApi: Backend {
         self.request_string(request::ConfigShow, None).await
     }
 
+    /// Show the current config of the server, parsed into a `KuboConfig`.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.config_show_typed();
+    /// ```
+    ///
+    async fn config_show_typed(&self) -> Result<config::KuboConfig, Self::Error> {
+        self.request(request::ConfigShow, None).await
+    }
+
+    /// Replace the config with a `KuboConfig`.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// # async fn run() -> Result<(), ipfs_api::Error> {
+    /// let client = IpfsClient::default();
+    /// let mut config = client.config_show_typed().await?;
+    ///
+    /// config.swarm.conn_mgr.high_water = Some(200);
+    /// client.config_replace_typed(&config).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    async fn config_replace_typed(
+        &self,
+        config: &config::KuboConfig,
+    ) -> Result<response::ConfigReplaceResponse, Self::Error> {
+        let config = serde_json::to_vec(config).map_err(crate::Error::Parse)?;
+
+        self.config_replace(Cursor::new(config)).await
+    }
+
+    /// Export a DAG rooted at `root` as a CAR (Content Addressable aRchive).
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let root = "bafkreiadxiqe4ugre3sgotaalycnqlueyijwm6ak6h2dxvkkg6aww2vtia";
+    /// let res = client
+    ///     .dag_export(root)
+    ///     .map_ok(|chunk| chunk.to_vec())
+    ///     .try_concat();
+    /// ```
+    ///
+    fn dag_export(&self, root: &str) -> BoxStream<Bytes, Self::Error> {
+        impl_stream_api_response! {
+            (self, request::DagExport { root }, None) => request_stream_bytes
+        }
+    }
+
     /// Returns information about a dag node in Ipfs.
     ///
     /// ```no_run
@@ -828,6 +1136,67 @@ pub trait IpfsApi: Backend {
         }
     }
 
+    /// Import the contents of a CAR (Content Addressable aRchive) file.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::fs::File;
+    ///
+    /// let client = IpfsClient::default();
+    /// let car = File::open("/path/to/file.car").unwrap();
+    /// let res = client.dag_import(car).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn dag_import<R>(&self, car: R) -> BoxStream<response::DagImportResponse, Self::Error>
+    where
+        R: 'static + Read + Send + Sync + Unpin,
+    {
+        self.dag_import_with_options(car, request::DagImport::default())
+    }
+
+    /// Import the contents of a CAR (Content Addressable aRchive) file with options.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::fs::File;
+    ///
+    /// let client = IpfsClient::default();
+    /// let car = File::open("/path/to/file.car").unwrap();
+    /// #[cfg(feature = "with-builder")]
+    /// let options = ipfs_api::request::DagImport::builder()
+    ///     .pin_roots(false)
+    ///     .stats(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let options = ipfs_api::request::DagImport {
+    ///     pin_roots: Some(false),
+    ///     stats: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let res = client
+    ///     .dag_import_with_options(car, options)
+    ///     .try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn dag_import_with_options<R>(
+        &self,
+        car: R,
+        options: request::DagImport,
+    ) -> BoxStream<response::DagImportResponse, Self::Error>
+    where
+        R: 'static + Read + Send + Sync + Unpin,
+    {
+        let mut form = multipart::Form::default();
+
+        form.add_reader("file", car);
+
+        impl_stream_api_response! {
+            (self, options, Some(form)) => request_stream_json
+        }
+    }
+
     /// Add a DAG node to Ipfs.
     ///
     /// ```no_run
@@ -878,8 +1247,67 @@ pub trait IpfsApi: Backend {
         self.request(options, Some(form)).await
     }
 
-    // TODO /dag/resolve
-
+    /// Resolve an IPLD path to the CID of the block containing it.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let path = "bafyreia4gfnyxbg6bzrj4pdqoxsrtoc6mxvrhvdq6bt6qrfwlfpxcwf4ju/a/b";
+    /// let res = client.dag_resolve(path);
+    /// ```
+    ///
+    async fn dag_resolve(&self, path: &str) -> Result<response::DagResolveResponse, Self::Error> {
+        self.request(request::DagResolve { path }, None).await
+    }
+
+    /// Gets stats for a DAG, streaming progress as the DAG is traversed.
+    ///
+    /// The last item in the stream holds the final size and block count.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let hash = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// let res = client.dag_stat(hash).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn dag_stat(&self, path: &str) -> BoxStream<response::DagStatResponse, Self::Error> {
+        self.dag_stat_with_options(request::DagStat {
+            path,
+            ..Default::default()
+        })
+    }
+
+    /// Gets stats for a DAG with options.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{request::DagStat, IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let hash = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// #[cfg(feature = "with-builder")]
+    /// let options = DagStat::builder().path(hash).progress(false).build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let options = DagStat {
+    ///     path: hash,
+    ///     progress: Some(false),
+    /// };
+    /// let res = client.dag_stat_with_options(options).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn dag_stat_with_options(
+        &self,
+        options: request::DagStat,
+    ) -> BoxStream<response::DagStatResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, options, None) => request_stream_json
+        }
+    }
+
     /// Query the DHT for all of the multiaddresses associated with a Peer ID.
     ///
     /// ```no_run
@@ -891,7 +1319,10 @@ pub trait IpfsApi: Backend {
     /// let res = client.dht_findpeer(peer).try_collect::<Vec<_>>();
     /// ```
     ///
-    fn dht_findpeer(&self, peer: &str) -> BoxStream<response::DhtFindPeerResponse, Self::Error> {
+    fn dht_findpeer<P>(&self, peer: &P) -> BoxStream<response::DhtFindPeerResponse, Self::Error>
+    where
+        P: ?Sized + Display + Sync,
+    {
         impl_stream_api_response! {
             (self, request::DhtFindPeer { peer }, None) => request_stream_json
         }
@@ -908,7 +1339,10 @@ pub trait IpfsApi: Backend {
     /// let res = client.dht_findprovs(key).try_collect::<Vec<_>>();
     /// ```
     ///
-    fn dht_findprovs(&self, key: &str) -> BoxStream<response::DhtFindProvsResponse, Self::Error> {
+    fn dht_findprovs<K>(&self, key: &K) -> BoxStream<response::DhtFindProvsResponse, Self::Error>
+    where
+        K: ?Sized + Display + Sync,
+    {
         impl_stream_api_response! {
             (self, request::DhtFindProvs { key }, None) => request_stream_json
         }
@@ -942,7 +1376,10 @@ pub trait IpfsApi: Backend {
     /// let res = client.dht_provide(key).try_collect::<Vec<_>>();
     /// ```
     ///
-    fn dht_provide(&self, key: &str) -> BoxStream<response::DhtProvideResponse, Self::Error> {
+    fn dht_provide<K>(&self, key: &K) -> BoxStream<response::DhtProvideResponse, Self::Error>
+    where
+        K: ?Sized + Display + Sync,
+    {
         impl_stream_api_response! {
             (self, request::DhtProvide { key }, None) => request_stream_json
         }
@@ -975,7 +1412,10 @@ pub trait IpfsApi: Backend {
     /// let res = client.dht_query(peer).try_collect::<Vec<_>>();
     /// ```
     ///
-    fn dht_query(&self, peer: &str) -> BoxStream<response::DhtQueryResponse, Self::Error> {
+    fn dht_query<P>(&self, peer: &P) -> BoxStream<response::DhtQueryResponse, Self::Error>
+    where
+        P: ?Sized + Display + Sync,
+    {
         impl_stream_api_response! {
             (self, request::DhtQuery { peer }, None) => request_stream_json
         }
@@ -1585,11 +2025,142 @@ pub trait IpfsApi: Backend {
     /// ```
     ///
     fn get(&self, path: &str) -> BoxStream<Bytes, Self::Error> {
+        self.get_with_options(request::Get {
+            path,
+            ..Default::default()
+        })
+    }
+
+    /// Download Ipfs object with options.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::Get::builder()
+    ///     .path("/test/dir")
+    ///     .archive(true)
+    ///     .compress(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::Get {
+    ///     path: "/test/dir",
+    ///     archive: Some(true),
+    ///     compress: Some(true),
+    ///     compression_level: None,
+    /// };
+    /// let res = client.get_with_options(req);
+    /// ```
+    ///
+    fn get_with_options(&self, options: request::Get) -> BoxStream<Bytes, Self::Error> {
         impl_stream_api_response! {
-            (self, request::Get { path }, None) => request_stream_bytes
+            (self, options, None) => request_stream_bytes
         }
     }
 
+    /// Download Ipfs object to a local path, reporting progress as the
+    /// download is written.
+    ///
+    /// Directories are extracted to `dest`, and files are written to `dest`.
+    /// Entries that would be written outside of `dest` are rejected. Files
+    /// are written on a separate thread, so the executor isn't blocked.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client
+    ///     .get_to_path("/test/dir", "./dir")
+    ///     .try_for_each(|progress| {
+    ///         eprintln!("{} bytes", progress.bytes);
+    ///
+    ///         futures::future::ok(())
+    ///     });
+    /// ```
+    ///
+    fn get_to_path<P>(&self, path: &str, dest: P) -> BoxStream<GetProgress, Self::Error>
+    where
+        P: AsRef<Path>,
+    {
+        self.get_to_path_with_options(
+            request::Get {
+                path,
+                ..Default::default()
+            },
+            dest,
+        )
+    }
+
+    /// Download Ipfs object to a local path with options, reporting progress
+    /// as the download is written.
+    ///
+    /// If `archive` or `compress` are set, the archive is saved to `dest`
+    /// as-is, instead of being extracted.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::Get::builder()
+    ///     .path("/test/dir")
+    ///     .archive(true)
+    ///     .compress(true)
+    ///     .compression_level(9)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::Get {
+    ///     path: "/test/dir",
+    ///     archive: Some(true),
+    ///     compress: Some(true),
+    ///     compression_level: Some(9),
+    /// };
+    /// let res = client
+    ///     .get_to_path_with_options(req, "./dir.tar.gz")
+    ///     .try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn get_to_path_with_options<P>(
+        &self,
+        options: request::Get,
+        dest: P,
+    ) -> BoxStream<GetProgress, Self::Error>
+    where
+        P: AsRef<Path>,
+    {
+        let archive = options.archive == Some(true) || options.compress == Some(true);
+        let writer = match GetWriter::spawn(dest.as_ref(), archive) {
+            Ok(writer) => writer,
+            Err(e) => return Box::new(future::err(e.into()).into_stream()),
+        };
+        let bytes = self.get_with_options(options);
+
+        let progress = stream::try_unfold(Some((bytes, writer)), |state| async move {
+            let (mut bytes, mut writer) = match state {
+                Some(state) => state,
+                None => return Ok(None),
+            };
+
+            match bytes.try_next().await? {
+                Some(chunk) => {
+                    let progress = writer.write(chunk).await?;
+
+                    Ok(Some((progress, Some((bytes, writer)))))
+                }
+                None => {
+                    let progress = writer.finish().await?;
+
+                    Ok(Some((progress, None)))
+                }
+            }
+        });
+
+        Box::new(Box::pin(progress))
+    }
+
     /// Returns information about a peer.
     ///
     /// If `peer` is `None`, returns information about you.
@@ -1606,8 +2177,30 @@ pub trait IpfsApi: Backend {
         self.request(request::Id { peer }, None).await
     }
 
+    /// Export a keypair.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient, KeyFormat};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client
+    ///     .key_export("test", KeyFormat::Libp2pProtobufCleartext)
+    ///     .map_ok(|chunk| chunk.to_vec())
+    ///     .try_concat();
+    /// ```
+    ///
+    fn key_export(&self, name: &str, format: request::KeyFormat) -> BoxStream<Bytes, Self::Error> {
+        impl_stream_api_response! {
+            (self, request::KeyExport { name, format: Some(format) }, None) => request_stream_bytes
+        }
+    }
+
     /// Create a new keypair.
     ///
+    /// The `size` is only sent for `KeyType::Rsa`, since Ed25519 keys have
+    /// a fixed size.
+    ///
     /// ```no_run
     /// use ipfs_api::{IpfsApi, IpfsClient, KeyType};
     ///
@@ -1621,8 +2214,53 @@ pub trait IpfsApi: Backend {
         kind: request::KeyType,
         size: i32,
     ) -> Result<response::KeyGenResponse, Self::Error> {
-        self.request(request::KeyGen { name, kind, size }, None)
-            .await
+        self.request(
+            request::KeyGen {
+                name,
+                kind,
+                size: kind.key_size(size),
+            },
+            None,
+        )
+        .await
+    }
+
+    /// Import a previously exported keypair.
+    ///
+    /// Set `allow_any_key_type` to import keys that cannot be used for IPNS.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient, KeyFormat};
+    /// use std::fs::File;
+    ///
+    /// let client = IpfsClient::default();
+    /// let key = File::open("test.key").unwrap();
+    /// let res = client.key_import("test", key, KeyFormat::Libp2pProtobufCleartext, false);
+    /// ```
+    ///
+    async fn key_import<R>(
+        &self,
+        name: &str,
+        key: R,
+        format: request::KeyFormat,
+        allow_any_key_type: bool,
+    ) -> Result<response::KeyImportResponse, Self::Error>
+    where
+        R: 'static + Read + Send + Sync + Unpin,
+    {
+        let mut form = multipart::Form::default();
+
+        form.add_reader("key", key);
+
+        self.request(
+            request::KeyImport {
+                name,
+                format: Some(format),
+                allow_any_key_type: Some(allow_any_key_type),
+            },
+            Some(form),
+        )
+        .await
     }
 
     /// List all local keypairs.
@@ -1670,6 +2308,47 @@ pub trait IpfsApi: Backend {
         self.request(request::KeyRm { name }, None).await
     }
 
+    /// Rotate the node's identity key, replacing it with a new keypair.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.key_rotate();
+    /// ```
+    ///
+    async fn key_rotate(&self) -> Result<response::KeyRotateResponse, Self::Error> {
+        self.key_rotate_with_options(request::KeyRotate::default())
+            .await
+    }
+
+    /// Rotate the node's identity key with options.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient, KeyType};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::KeyRotate::builder()
+    ///     .oldkey("old-self")
+    ///     .kind(KeyType::Ed25519)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::KeyRotate {
+    ///     oldkey: Some("old-self"),
+    ///     kind: Some(KeyType::Ed25519),
+    ///     size: None,
+    /// };
+    /// let res = client.key_rotate_with_options(req);
+    /// ```
+    ///
+    async fn key_rotate_with_options(
+        &self,
+        options: request::KeyRotate<'async_trait>,
+    ) -> Result<response::KeyRotateResponse, Self::Error> {
+        self.request(options, None).await
+    }
+
     /// Change the logging level for a logger.
     ///
     /// ```no_run
@@ -1777,16 +2456,50 @@ pub trait IpfsApi: Backend {
 
     // TODO /mount
 
+    /// Decode and inspect a raw IPNS record.
+    ///
+    /// If `verify` is set to the CID of a public IPNS key, the record is
+    /// also validated against that key.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::fs::File;
+    ///
+    /// let client = IpfsClient::default();
+    /// let record = File::open("record.ipns-record").unwrap();
+    /// let res = client.name_inspect(
+    ///     record,
+    ///     Some("k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8")
+    /// );
+    /// ```
+    ///
+    async fn name_inspect<R>(
+        &self,
+        record: R,
+        verify: Option<&str>,
+    ) -> Result<response::NameInspectResponse, Self::Error>
+    where
+        R: 'static + Read + Send + Sync + Unpin,
+    {
+        let mut form = multipart::Form::default();
+
+        form.add_reader("record", record);
+
+        self.request(request::NameInspect { verify, dump: None }, Some(form))
+            .await
+    }
+
     /// Publish an IPFS path to IPNS.
     ///
     /// ```no_run
     /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::time::Duration;
     ///
     /// let client = IpfsClient::default();
     /// let res = client.name_publish(
     ///     "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
     ///     false,
-    ///     Some("12h"),
+    ///     Some(Duration::from_secs(12 * 60 * 60)),
     ///     None,
     ///     None
     /// );
@@ -1796,78 +2509,152 @@ pub trait IpfsApi: Backend {
         &self,
         path: &str,
         resolve: bool,
-        lifetime: Option<&str>,
-        ttl: Option<&str>,
+        lifetime: Option<Duration>,
+        ttl: Option<Duration>,
         key: Option<&str>,
     ) -> Result<response::NamePublishResponse, Self::Error> {
-        self.request(
-            request::NamePublish {
-                path,
-                resolve,
-                lifetime,
-                ttl,
-                key,
-            },
-            None,
-        )
+        self.name_publish_with_options(request::NamePublish {
+            path,
+            resolve: Some(resolve),
+            lifetime,
+            ttl,
+            key,
+            ..Default::default()
+        })
         .await
     }
 
-    /// Resolve an IPNS name.
+    /// Publish an IPFS path to IPNS with options.
     ///
     /// ```no_run
     /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::time::Duration;
     ///
     /// let client = IpfsClient::default();
-    /// let res = client.name_resolve(
-    ///     Some("/ipns/ipfs.io"),
-    ///     true,
-    ///     false
-    /// );
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::NamePublish::builder()
+    ///     .path("/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY")
+    ///     .lifetime(Duration::from_secs(24 * 60 * 60))
+    ///     .allow_offline(true)
+    ///     .quieter(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::NamePublish {
+    ///     path: "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
+    ///     lifetime: Some(Duration::from_secs(24 * 60 * 60)),
+    ///     allow_offline: Some(true),
+    ///     quieter: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let res = client.name_publish_with_options(req);
     /// ```
     ///
-    async fn name_resolve(
+    async fn name_publish_with_options(
         &self,
-        name: Option<&str>,
-        recursive: bool,
-        nocache: bool,
-    ) -> Result<response::NameResolveResponse, Self::Error> {
-        self.request(
-            request::NameResolve {
-                name,
-                recursive,
-                nocache,
-            },
-            None,
-        )
-        .await
+        options: request::NamePublish<'_>,
+    ) -> Result<response::NamePublishResponse, Self::Error> {
+        self.request(options, None).await
     }
 
-    /// Output the raw bytes of an Ipfs object.
+    /// Cancel a name subscription.
     ///
     /// ```no_run
     /// use ipfs_api::{IpfsApi, IpfsClient};
     ///
     /// let client = IpfsClient::default();
-    /// let res = client.object_data("/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY");
+    /// let res = client.name_pubsub_cancel(
+    ///     "/ipns/k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8"
+    /// );
     /// ```
     ///
-    fn object_data(&self, key: &str) -> BoxStream<Bytes, Self::Error> {
-        impl_stream_api_response! {
-            (self, request::ObjectData { key }, None) => request_stream_bytes
-        }
+    async fn name_pubsub_cancel(
+        &self,
+        name: &str,
+    ) -> Result<response::NamePubsubCancelResponse, Self::Error> {
+        self.request(request::NamePubsubCancel { name }, None).await
     }
 
-    /// Returns the diff of two Ipfs objects.
+    /// Query the state of IPNS over PubSub.
     ///
     /// ```no_run
     /// use ipfs_api::{IpfsApi, IpfsClient};
     ///
     /// let client = IpfsClient::default();
-    /// let res = client.object_diff(
-    ///     "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
-    ///     "/ipfs/QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA"
-    /// );
+    /// let res = client.name_pubsub_state();
+    /// ```
+    ///
+    async fn name_pubsub_state(&self) -> Result<response::NamePubsubStateResponse, Self::Error> {
+        self.request(request::NamePubsubState, None).await
+    }
+
+    /// Show current name subscriptions.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.name_pubsub_subs();
+    /// ```
+    ///
+    async fn name_pubsub_subs(&self) -> Result<response::NamePubsubSubsResponse, Self::Error> {
+        self.request(request::NamePubsubSubs, None).await
+    }
+
+    /// Resolve an IPNS name.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.name_resolve(
+    ///     Some("/ipns/ipfs.io"),
+    ///     true,
+    ///     false
+    /// );
+    /// ```
+    ///
+    async fn name_resolve(
+        &self,
+        name: Option<&str>,
+        recursive: bool,
+        nocache: bool,
+    ) -> Result<response::NameResolveResponse, Self::Error> {
+        self.request(
+            request::NameResolve {
+                name,
+                recursive,
+                nocache,
+            },
+            None,
+        )
+        .await
+    }
+
+    /// Output the raw bytes of an Ipfs object.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.object_data("/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY");
+    /// ```
+    ///
+    fn object_data(&self, key: &str) -> BoxStream<Bytes, Self::Error> {
+        impl_stream_api_response! {
+            (self, request::ObjectData { key }, None) => request_stream_bytes
+        }
+    }
+
+    /// Returns the diff of two Ipfs objects.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.object_diff(
+    ///     "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
+    ///     "/ipfs/QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA"
+    /// );
     /// ```
     ///
     async fn object_diff(
@@ -1999,8 +2786,7 @@ pub trait IpfsApi: Backend {
     /// pin just the top-level object, or all sub-objects
     /// it depends on.  For most cases you want it to be `true`.
     ///
-    /// Does not yet implement the "progress" agument because
-    /// reading it is kinda squirrelly.
+    /// Use `pin_add_with_options` to stream progress updates.
     ///
     /// # Examples
     ///
@@ -2027,6 +2813,40 @@ pub trait IpfsApi: Backend {
         .await
     }
 
+    /// Pins a new object with options, streaming progress updates.
+    ///
+    /// When `progress` is set, the server reports the number of nodes
+    /// pinned so far, followed by a final response listing the pins.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::PinAdd::builder()
+    ///     .key("QmaCpDMGvV2BGHeYERUEnRQAwe3N8SzbUtfsmvsqQLuvuJ")
+    ///     .recursive(true)
+    ///     .progress(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::PinAdd {
+    ///     key: "QmaCpDMGvV2BGHeYERUEnRQAwe3N8SzbUtfsmvsqQLuvuJ",
+    ///     recursive: Some(true),
+    ///     progress: true,
+    /// };
+    /// let res = client.pin_add_with_options(req).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn pin_add_with_options(
+        &self,
+        options: request::PinAdd,
+    ) -> BoxStream<response::PinAddResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, options, None) => request_stream_json
+        }
+    }
+
     /// Returns a list of pinned objects in local storage.
     ///
     /// ```no_run
@@ -2049,6 +2869,190 @@ pub trait IpfsApi: Backend {
         self.request(request::PinLs { key, typ }, None).await
     }
 
+    /// Pin an object to a remote pinning service.
+    ///
+    /// The service must first be registered with [`pin_remote_service_add`].
+    ///
+    /// [`pin_remote_service_add`]: IpfsApi::pin_remote_service_add
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.pin_remote_add(
+    ///     "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
+    ///     "mysrv"
+    /// );
+    /// ```
+    ///
+    async fn pin_remote_add(
+        &self,
+        path: &str,
+        service: &str,
+    ) -> Result<response::PinRemoteAddResponse, Self::Error> {
+        self.pin_remote_add_with_options(request::PinRemoteAdd {
+            path,
+            service,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Pin an object to a remote pinning service with options.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::PinRemoteAdd::builder()
+    ///     .path("/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY")
+    ///     .service("mysrv")
+    ///     .name("backup")
+    ///     .background(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::PinRemoteAdd {
+    ///     path: "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
+    ///     service: "mysrv",
+    ///     name: Some("backup"),
+    ///     background: Some(true),
+    /// };
+    /// let res = client.pin_remote_add_with_options(req);
+    /// ```
+    ///
+    async fn pin_remote_add_with_options(
+        &self,
+        options: request::PinRemoteAdd<'_>,
+    ) -> Result<response::PinRemoteAddResponse, Self::Error> {
+        self.request(options, None).await
+    }
+
+    /// List objects pinned to a remote pinning service.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{request::PinRemoteStatus, IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let status = [PinRemoteStatus::Queued, PinRemoteStatus::Pinning];
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::PinRemoteLs::builder()
+    ///     .service("mysrv")
+    ///     .status(&status)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::PinRemoteLs {
+    ///     service: "mysrv",
+    ///     status: &status,
+    ///     ..Default::default()
+    /// };
+    /// let res = client.pin_remote_ls(req).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn pin_remote_ls(
+        &self,
+        options: request::PinRemoteLs,
+    ) -> BoxStream<response::PinRemoteLsResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, options, None) => request_stream_json
+        }
+    }
+
+    /// Remove pins from a remote pinning service.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let cid = ["QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY"];
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::PinRemoteRm::builder()
+    ///     .service("mysrv")
+    ///     .cid(&cid)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::PinRemoteRm {
+    ///     service: "mysrv",
+    ///     cid: &cid,
+    ///     ..Default::default()
+    /// };
+    /// let res = client.pin_remote_rm(req);
+    /// ```
+    ///
+    async fn pin_remote_rm(
+        &self,
+        options: request::PinRemoteRm<'_>,
+    ) -> Result<response::PinRemoteRmResponse, Self::Error> {
+        self.request_empty(options, None).await
+    }
+
+    /// Add a remote pinning service.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.pin_remote_service_add(
+    ///     "mysrv",
+    ///     "https://pinning.example.com/api/v1",
+    ///     "secret"
+    /// );
+    /// ```
+    ///
+    async fn pin_remote_service_add(
+        &self,
+        service: &str,
+        endpoint: &str,
+        key: &str,
+    ) -> Result<response::PinRemoteServiceAddResponse, Self::Error> {
+        self.request_empty(
+            request::PinRemoteServiceAdd {
+                service,
+                endpoint,
+                key,
+            },
+            None,
+        )
+        .await
+    }
+
+    /// List remote pinning services.
+    ///
+    /// If `stat` is `true`, the pin counts on each service are fetched as well.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.pin_remote_service_ls(true);
+    /// ```
+    ///
+    async fn pin_remote_service_ls(
+        &self,
+        stat: bool,
+    ) -> Result<response::PinRemoteServiceLsResponse, Self::Error> {
+        self.request(request::PinRemoteServiceLs { stat }, None)
+            .await
+    }
+
+    /// Remove a remote pinning service.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.pin_remote_service_rm("mysrv");
+    /// ```
+    ///
+    async fn pin_remote_service_rm(
+        &self,
+        service: &str,
+    ) -> Result<response::PinRemoteServiceRmResponse, Self::Error> {
+        self.request_empty(request::PinRemoteServiceRm { service }, None)
+            .await
+    }
+
     /// Removes a pinned object from local storage.
     ///
     /// ```no_run
@@ -2073,9 +3077,79 @@ pub trait IpfsApi: Backend {
         self.request(request::PinRm { key, recursive }, None).await
     }
 
-    // TODO /pin/update
+    /// Updates a recursive pin from one object to another.
+    ///
+    /// If `unpin` is `false`, the old pin is kept.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.pin_update(
+    ///     "QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm",
+    ///     "QmVLDAhCY3X9P2uRudKAryuQFPM5zqA3Yij1dY8FpGbL7T",
+    ///     true
+    /// );
+    /// ```
+    ///
+    async fn pin_update(
+        &self,
+        from: &str,
+        to: &str,
+        unpin: bool,
+    ) -> Result<response::PinUpdateResponse, Self::Error> {
+        self.request(
+            request::PinUpdate {
+                from,
+                to,
+                unpin: Some(unpin),
+            },
+            None,
+        )
+        .await
+    }
+
+    /// Verifies that recursive pins are complete.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.pin_verify().try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn pin_verify(&self) -> BoxStream<response::PinVerifyResponse, Self::Error> {
+        self.pin_verify_with_options(request::PinVerify::default())
+    }
 
-    // TODO /pin/verify
+    /// Verifies that recursive pins are complete, with options.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::PinVerify::builder()
+    ///     .verbose(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::PinVerify {
+    ///     verbose: Some(true),
+    ///     quiet: None,
+    /// };
+    /// let res = client.pin_verify_with_options(req).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn pin_verify_with_options(
+        &self,
+        options: request::PinVerify,
+    ) -> BoxStream<response::PinVerifyResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, options, None) => request_stream_json
+        }
+    }
 
     /// Pings a peer.
     ///
@@ -2176,6 +3250,30 @@ pub trait IpfsApi: Backend {
         .await
     }
 
+    /// Publish a message held in memory to a topic.
+    ///
+    /// ```no_run
+    /// use bytes::Bytes;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.pubsub_pub_bytes("feed", &b"Hello World!"[..]);
+    /// let res = client.pubsub_pub_bytes("feed", vec![0, 159, 146, 150]);
+    /// let res = client.pubsub_pub_bytes("feed", Bytes::copy_from_slice(&[0, 1, 2]));
+    /// ```
+    ///
+    async fn pubsub_pub_bytes<T, D>(
+        &self,
+        topic: T,
+        data: D,
+    ) -> Result<response::PubsubPubResponse, Self::Error>
+    where
+        T: AsRef<[u8]> + Send + Sync,
+        D: Into<Bytes> + Send,
+    {
+        self.pubsub_pub(topic, Cursor::new(data.into())).await
+    }
+
     /// Subscribes to a pubsub topic.
     ///
     /// ```no_run
@@ -2195,7 +3293,38 @@ pub trait IpfsApi: Backend {
         }
     }
 
-    /// Gets a list of local references.
+    /// Subscribes to a pubsub topic, re-subscribing with backoff whenever
+    /// the subscription is lost.
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient, SubscriptionEvent};
+    /// use std::time::Duration;
+    ///
+    /// # async fn run() {
+    /// let client = IpfsClient::default();
+    /// let mut sub = client
+    ///     .pubsub_sub_resilient("feed")
+    ///     .with_backoff(Duration::from_secs(1), Duration::from_secs(60));
+    ///
+    /// while let Some(event) = sub.next().await {
+    ///     match event {
+    ///         SubscriptionEvent::Message(msg) => eprintln!("{}: {:?}", msg.from, msg.data),
+    ///         SubscriptionEvent::Disconnected(e) => eprintln!("disconnected: {:?}", e),
+    ///         SubscriptionEvent::Reconnected => eprintln!("reconnected"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    fn pubsub_sub_resilient<T>(&self, topic: T) -> ResilientSubscription<'_, Self>
+    where
+        T: AsRef<[u8]>,
+    {
+        ResilientSubscription::new(self, topic.as_ref())
+    }
+
+    /// Gets a list of local references.
     ///
     /// ```no_run
     /// use ipfs_api::{IpfsApi, IpfsClient};
@@ -2210,17 +3339,524 @@ pub trait IpfsApi: Backend {
         }
     }
 
-    // TODO /repo/fsck
+    /// Remove repo lockfiles.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.repo_fsck();
+    /// ```
+    ///
+    async fn repo_fsck(&self) -> Result<response::RepoFsckResponse, Self::Error> {
+        self.request(request::RepoFsck, None).await
+    }
+
+    /// Perform a garbage collection sweep on the repo.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.repo_gc().try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn repo_gc(&self) -> BoxStream<response::RepoGcResponse, Self::Error> {
+        self.repo_gc_with_options(request::RepoGc::default())
+    }
+
+    /// Perform a garbage collection sweep on the repo with options.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::RepoGc::builder()
+    ///     .stream_errors(true)
+    ///     .quiet(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::RepoGc {
+    ///     stream_errors: Some(true),
+    ///     quiet: Some(true),
+    /// };
+    /// let res = client.repo_gc_with_options(req).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn repo_gc_with_options(
+        &self,
+        options: request::RepoGc,
+    ) -> BoxStream<response::RepoGcResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, options, None) => request_stream_json
+        }
+    }
+
+    /// Get stats for the currently used repo.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.repo_stat();
+    /// ```
+    ///
+    async fn repo_stat(&self) -> Result<response::RepoStatResponse, Self::Error> {
+        self.repo_stat_with_options(request::RepoStat::default())
+            .await
+    }
+
+    /// Get stats for the currently used repo with options.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::RepoStat::builder()
+    ///     .size_only(true)
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::RepoStat {
+    ///     size_only: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let res = client.repo_stat_with_options(req);
+    /// ```
+    ///
+    async fn repo_stat_with_options(
+        &self,
+        options: request::RepoStat,
+    ) -> Result<response::RepoStatResponse, Self::Error> {
+        self.request(options, None).await
+    }
+
+    /// Verify all blocks in repo are not corrupted.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.repo_verify().try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn repo_verify(&self) -> BoxStream<response::RepoVerifyResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, request::RepoVerify, None) => request_stream_json
+        }
+    }
+
+    /// Show the repo version.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.repo_version();
+    /// ```
+    ///
+    async fn repo_version(&self) -> Result<response::RepoVersionResponse, Self::Error> {
+        self.request(request::RepoVersion, None).await
+    }
+
+    /// Resolve the value of names to IPFS.
+    ///
+    /// Works with `/ipns/` names, dnslink domains and `/ipfs/` paths.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.resolve("/ipns/ipfs.io", true);
+    /// ```
+    ///
+    async fn resolve(
+        &self,
+        path: &str,
+        recursive: bool,
+    ) -> Result<response::ResolveResponse, Self::Error> {
+        self.resolve_with_options(request::Resolve {
+            path,
+            recursive: Some(recursive),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Resolve the value of names to IPFS with options.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::time::Duration;
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::Resolve::builder()
+    ///     .path("/ipns/ipfs.io")
+    ///     .dht_record_count(8)
+    ///     .dht_timeout(Duration::from_secs(30))
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::Resolve {
+    ///     path: "/ipns/ipfs.io",
+    ///     dht_record_count: Some(8),
+    ///     dht_timeout: Some(Duration::from_secs(30)),
+    ///     ..Default::default()
+    /// };
+    /// let res = client.resolve_with_options(req);
+    /// ```
+    ///
+    async fn resolve_with_options(
+        &self,
+        options: request::Resolve<'_>,
+    ) -> Result<response::ResolveResponse, Self::Error> {
+        self.request(options, None).await
+    }
+
+    /// Find the multiaddresses associated with a peer ID.
+    ///
+    /// Requires Kubo 0.16 or newer. See `routing_findpeer_compat` for older daemons.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let peer = "QmSoLPppuBtQSGwKDZT2M73ULpjvfd3aZ6ha4oFGL1KrGM";
+    /// let res = client.routing_findpeer(peer).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn routing_findpeer<P>(
+        &self,
+        peer: &P,
+    ) -> BoxStream<response::RoutingFindPeerResponse, Self::Error>
+    where
+        P: ?Sized + Display + Sync,
+    {
+        impl_stream_api_response! {
+            (self, request::RoutingFindPeer { peer }, None) => request_stream_json
+        }
+    }
+
+    /// Find peers that can provide a specific value given a key.
+    ///
+    /// Requires Kubo 0.16 or newer. See `routing_findprovs_compat` for older daemons.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let key = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// let res = client.routing_findprovs(key).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn routing_findprovs<K>(
+        &self,
+        key: &K,
+    ) -> BoxStream<response::RoutingFindProvsResponse, Self::Error>
+    where
+        K: ?Sized + Display + Sync,
+    {
+        impl_stream_api_response! {
+            (self, request::RoutingFindProvs { key }, None) => request_stream_json
+        }
+    }
 
-    // TODO /repo/gc
+    /// Query the routing system for the value of a given key.
+    ///
+    /// Requires Kubo 0.16 or newer. See `routing_get_compat` for older daemons.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let key = "/ipns/k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8";
+    /// let res = client.routing_get(key).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn routing_get(&self, key: &str) -> BoxStream<response::RoutingGetResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, request::RoutingGet { key }, None) => request_stream_json
+        }
+    }
 
-    // TODO /repo/stat
+    /// Announce to the network that you are providing a given value.
+    ///
+    /// Requires Kubo 0.16 or newer. See `routing_provide_compat` for older daemons.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let key = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// let res = client.routing_provide(key).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn routing_provide<K>(
+        &self,
+        key: &K,
+    ) -> BoxStream<response::RoutingProvideResponse, Self::Error>
+    where
+        K: ?Sized + Display + Sync,
+    {
+        impl_stream_api_response! {
+            (self, request::RoutingProvide { key }, None) => request_stream_json
+        }
+    }
+
+    /// Write a key/value pair to the routing system.
+    ///
+    /// Requires Kubo 0.16 or newer. See `routing_put_compat` for older daemons.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::fs::File;
+    ///
+    /// let client = IpfsClient::default();
+    /// let key = "/ipns/k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8";
+    /// let record = File::open("record.ipns-record").unwrap();
+    /// let res = client.routing_put(key, record).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn routing_put<R>(
+        &self,
+        key: &str,
+        value: R,
+    ) -> BoxStream<response::RoutingPutResponse, Self::Error>
+    where
+        R: 'static + Read + Send + Sync + Unpin,
+    {
+        let mut form = multipart::Form::default();
+
+        form.add_reader("value-file", value);
+
+        impl_stream_api_response! {
+            (self, request::RoutingPut { key }, Some(form)) => request_stream_json
+        }
+    }
+
+    /// Returns whether the daemon serves the `/routing/*` endpoints, which
+    /// replaced `/dht/*` in Kubo 0.16.
+    ///
+    /// This sends a `version` request the first time it's called. The answer
+    /// is then kept by the backend, if it supports that.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.has_routing_api();
+    /// ```
+    ///
+    async fn has_routing_api(&self) -> Result<bool, Self::Error> {
+        if let Some(has_routing_api) = self.routing_api_cache().and_then(|cache| cache.get()) {
+            return Ok(*has_routing_api);
+        }
 
-    // TODO /repo/verify
+        let has_routing_api = self.version().await?.has_routing_api();
 
-    // TODO /repo/version
+        if let Some(cache) = self.routing_api_cache() {
+            let _ = cache.set(has_routing_api);
+        }
 
-    // TODO /resolve
+        Ok(has_routing_api)
+    }
+
+    /// Like `routing_findpeer`, but falls back to `dht_findpeer` if the daemon
+    /// is older than Kubo 0.16.
+    ///
+    /// The first call to one of the `_compat` methods looks up the daemon
+    /// version with an extra request. See `has_routing_api`.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// # async fn example() {
+    /// let client = IpfsClient::default();
+    /// let peer = "QmSoLPppuBtQSGwKDZT2M73ULpjvfd3aZ6ha4oFGL1KrGM";
+    /// let res = client
+    ///     .routing_findpeer_compat(peer)
+    ///     .await
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    async fn routing_findpeer_compat<P>(
+        &self,
+        peer: &P,
+    ) -> BoxStream<response::RoutingFindPeerResponse, Self::Error>
+    where
+        P: ?Sized + Display + Sync,
+    {
+        match self.has_routing_api().await {
+            Ok(true) => self.routing_findpeer(peer),
+            Ok(false) => self.dht_findpeer(peer),
+            Err(e) => Box::new(future::err(e).into_stream()),
+        }
+    }
+
+    /// Like `routing_findprovs`, but falls back to `dht_findprovs` if the daemon
+    /// is older than Kubo 0.16.
+    ///
+    /// The first call to one of the `_compat` methods looks up the daemon
+    /// version with an extra request. See `has_routing_api`.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// # async fn example() {
+    /// let client = IpfsClient::default();
+    /// let key = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// let res = client
+    ///     .routing_findprovs_compat(key)
+    ///     .await
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    async fn routing_findprovs_compat<K>(
+        &self,
+        key: &K,
+    ) -> BoxStream<response::RoutingFindProvsResponse, Self::Error>
+    where
+        K: ?Sized + Display + Sync,
+    {
+        match self.has_routing_api().await {
+            Ok(true) => self.routing_findprovs(key),
+            Ok(false) => self.dht_findprovs(key),
+            Err(e) => Box::new(future::err(e).into_stream()),
+        }
+    }
+
+    /// Like `routing_get`, but falls back to `dht_get` if the daemon
+    /// is older than Kubo 0.16.
+    ///
+    /// The first call to one of the `_compat` methods looks up the daemon
+    /// version with an extra request. See `has_routing_api`.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// # async fn example() {
+    /// let client = IpfsClient::default();
+    /// let key = "/ipns/k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8";
+    /// let res = client
+    ///     .routing_get_compat(key)
+    ///     .await
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    async fn routing_get_compat(
+        &self,
+        key: &str,
+    ) -> BoxStream<response::RoutingGetResponse, Self::Error> {
+        match self.has_routing_api().await {
+            Ok(true) => self.routing_get(key),
+            Ok(false) => self.dht_get(key),
+            Err(e) => Box::new(future::err(e).into_stream()),
+        }
+    }
+
+    /// Like `routing_provide`, but falls back to `dht_provide` if the daemon
+    /// is older than Kubo 0.16.
+    ///
+    /// The first call to one of the `_compat` methods looks up the daemon
+    /// version with an extra request. See `has_routing_api`.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// # async fn example() {
+    /// let client = IpfsClient::default();
+    /// let key = "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA";
+    /// let res = client
+    ///     .routing_provide_compat(key)
+    ///     .await
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    async fn routing_provide_compat<K>(
+        &self,
+        key: &K,
+    ) -> BoxStream<response::RoutingProvideResponse, Self::Error>
+    where
+        K: ?Sized + Display + Sync,
+    {
+        match self.has_routing_api().await {
+            Ok(true) => self.routing_provide(key),
+            Ok(false) => self.dht_provide(key),
+            Err(e) => Box::new(future::err(e).into_stream()),
+        }
+    }
+
+    /// Like `routing_put`, but falls back to `dht_put` if the daemon is
+    /// older than Kubo 0.16.
+    ///
+    /// The first call to one of the `_compat` methods looks up the daemon
+    /// version with an extra request. See `has_routing_api`.
+    ///
+    /// Older daemons take the value as a query string argument, so it must be
+    /// valid UTF-8 in that case.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::io::Cursor;
+    ///
+    /// # async fn example() {
+    /// let client = IpfsClient::default();
+    /// let res = client
+    ///     .routing_put_compat("test", Cursor::new("Hello World!"))
+    ///     .await
+    ///     .try_collect::<Vec<_>>()
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    async fn routing_put_compat<R>(
+        &self,
+        key: &str,
+        mut value: R,
+    ) -> BoxStream<response::RoutingPutResponse, Self::Error>
+    where
+        R: 'static + Read + Send + Sync + Unpin,
+    {
+        match self.has_routing_api().await {
+            Ok(true) => self.routing_put(key, value),
+            Ok(false) => {
+                let mut buf = Vec::new();
+
+                let value = value
+                    .read_to_end(&mut buf)
+                    .map_err(crate::Error::from)
+                    .and_then(|_| String::from_utf8(buf).map_err(crate::Error::from));
+
+                match value {
+                    Ok(value) => self.dht_put(key, &value),
+                    Err(e) => Box::new(future::err(e.into()).into_stream()),
+                }
+            }
+            Err(e) => Box::new(future::err(e).into_stream()),
+        }
+    }
 
     /// Shutdown the Ipfs daemon.
     ///
@@ -2258,7 +3894,79 @@ pub trait IpfsApi: Backend {
     /// ```
     ///
     async fn stats_bw(&self) -> Result<response::StatsBwResponse, Self::Error> {
-        self.request(request::StatsBw, None).await
+        self.request(request::StatsBw::default(), None).await
+    }
+
+    /// Returns a stream of bandwidth stats.
+    ///
+    /// When `poll` is set, the server keeps sending updates every `interval`
+    /// until the stream is dropped. Otherwise the stream yields a single
+    /// snapshot.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    /// use std::time::Duration;
+    ///
+    /// let client = IpfsClient::default();
+    /// #[cfg(feature = "with-builder")]
+    /// let req = ipfs_api::request::StatsBw::builder()
+    ///     .proto("/ipfs/bitswap/1.2.0")
+    ///     .poll(true)
+    ///     .interval(Duration::from_secs(5))
+    ///     .build();
+    /// #[cfg(not(feature = "with-builder"))]
+    /// let req = ipfs_api::request::StatsBw {
+    ///     proto: Some("/ipfs/bitswap/1.2.0"),
+    ///     poll: Some(true),
+    ///     interval: Some(Duration::from_secs(5)),
+    ///     ..Default::default()
+    /// };
+    /// let res = client.stats_bw_stream(req).try_for_each(|stats| {
+    ///     println!("in: {} B/s, out: {} B/s", stats.rate_in, stats.rate_out);
+    ///     futures::future::ok(())
+    /// });
+    /// ```
+    ///
+    fn stats_bw_stream(
+        &self,
+        options: request::StatsBw,
+    ) -> BoxStream<response::StatsBwResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, options, None) => request_stream_json
+        }
+    }
+
+    /// Returns the routing tables of the DHTs, one response per DHT.
+    ///
+    /// If `dht` is `None`, reports on both the `wan` and `lan` DHTs.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.stats_dht(None).try_collect::<Vec<_>>();
+    /// let res = client.stats_dht(Some("wan")).try_collect::<Vec<_>>();
+    /// ```
+    ///
+    fn stats_dht(&self, dht: Option<&str>) -> BoxStream<response::StatsDhtResponse, Self::Error> {
+        impl_stream_api_response! {
+            (self, request::StatsDht { dht }, None) => request_stream_json
+        }
+    }
+
+    /// Returns reprovider stats.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.stats_provide();
+    /// ```
+    ///
+    async fn stats_provide(&self) -> Result<response::StatsProvideResponse, Self::Error> {
+        self.request(request::StatsProvide, None).await
     }
 
     /// Returns repo stats.
@@ -2274,7 +3982,31 @@ pub trait IpfsApi: Backend {
         self.request(request::StatsRepo, None).await
     }
 
-    // TODO /swarm/addrs/listen
+    /// List known addresses of connected peers.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_addrs();
+    /// ```
+    ///
+    async fn swarm_addrs(&self) -> Result<response::SwarmAddrsResponse, Self::Error> {
+        self.request(request::SwarmAddrs, None).await
+    }
+
+    /// Return a list of interface addresses the node is listening on.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_addrs_listen();
+    /// ```
+    ///
+    async fn swarm_addrs_listen(&self) -> Result<response::SwarmAddrsListenResponse, Self::Error> {
+        self.request(request::SwarmAddrsListen, None).await
+    }
 
     /// Return a list of local addresses.
     ///
@@ -2298,18 +4030,81 @@ pub trait IpfsApi: Backend {
     /// let res = client.swarm_connect("/dns4/production-ipfs-cluster-us-east-1-node2.runfission.com/tcp/4003/wss/p2p/12D3KooWQ2hL9NschcJ1Suqa1TybJc2ZaacqoQMBT3ziFC7Ye2BZ");
     /// ```
     ///
-    async fn swarm_connect(
+    async fn swarm_connect<P>(
         &self,
-        peer: &str,
-    ) -> Result<response::SwarmConnectResponse, Self::Error> {
+        peer: &P,
+    ) -> Result<response::SwarmConnectResponse, Self::Error>
+    where
+        P: ?Sized + Display + Sync,
+    {
         self.request(request::SwarmConnect { peer }, None).await
     }
 
-    // TODO /swarm/disconnect
+    /// Close the connection to a given address.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_disconnect("/ip4/104.131.131.82/tcp/4001/p2p/QmaCpDMGvV2BGHeYERUEnRQAwe3N8SzbUtfsmvsqQLuvuJ");
+    /// ```
+    ///
+    async fn swarm_disconnect<A>(
+        &self,
+        address: &A,
+    ) -> Result<response::SwarmDisconnectResponse, Self::Error>
+    where
+        A: ?Sized + Display + Sync,
+    {
+        self.request(request::SwarmDisconnect { address }, None)
+            .await
+    }
+
+    /// List the address filters in use.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_filters();
+    /// ```
+    ///
+    async fn swarm_filters(&self) -> Result<response::SwarmFiltersResponse, Self::Error> {
+        self.request(request::SwarmFilters, None).await
+    }
 
-    // TODO /swarm/filters/add
+    /// Add an address filter, given in `/ip4/<addr>/ipcidr/<bits>` form.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_filters_add("/ip4/192.168.0.0/ipcidr/16");
+    /// ```
+    ///
+    async fn swarm_filters_add(
+        &self,
+        filter: &str,
+    ) -> Result<response::SwarmFiltersAddResponse, Self::Error> {
+        self.request(request::SwarmFiltersAdd { filter }, None)
+            .await
+    }
 
-    // TODO /swarm/filters/rm
+    /// Remove an address filter.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_filters_rm("/ip4/192.168.0.0/ipcidr/16");
+    /// ```
+    ///
+    async fn swarm_filters_rm(
+        &self,
+        filter: &str,
+    ) -> Result<response::SwarmFiltersRmResponse, Self::Error> {
+        self.request(request::SwarmFiltersRm { filter }, None).await
+    }
 
     /// Return a list of peers with open connections.
     ///
@@ -2324,6 +4119,58 @@ pub trait IpfsApi: Backend {
         self.request(request::SwarmPeers, None).await
     }
 
+    /// Add a peer to the peering subsystem, which keeps a connection to it open.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_peering_add("/ip4/104.131.131.82/tcp/4001/p2p/QmaCpDMGvV2BGHeYERUEnRQAwe3N8SzbUtfsmvsqQLuvuJ");
+    /// ```
+    ///
+    async fn swarm_peering_add<A>(
+        &self,
+        address: &A,
+    ) -> Result<response::SwarmPeeringAddResponse, Self::Error>
+    where
+        A: ?Sized + Display + Sync,
+    {
+        self.request(request::SwarmPeeringAdd { address }, None)
+            .await
+    }
+
+    /// List peers registered in the peering subsystem.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_peering_ls();
+    /// ```
+    ///
+    async fn swarm_peering_ls(&self) -> Result<response::SwarmPeeringLsResponse, Self::Error> {
+        self.request(request::SwarmPeeringLs, None).await
+    }
+
+    /// Remove a peer from the peering subsystem.
+    ///
+    /// ```no_run
+    /// use ipfs_api::{IpfsApi, IpfsClient};
+    ///
+    /// let client = IpfsClient::default();
+    /// let res = client.swarm_peering_rm("QmaCpDMGvV2BGHeYERUEnRQAwe3N8SzbUtfsmvsqQLuvuJ");
+    /// ```
+    ///
+    async fn swarm_peering_rm<P>(
+        &self,
+        peer: &P,
+    ) -> Result<response::SwarmPeeringRmResponse, Self::Error>
+    where
+        P: ?Sized + Display + Sync,
+    {
+        self.request(request::SwarmPeeringRm { peer }, None).await
+    }
+
     /// Add a tar file to Ipfs.
     ///
     /// Note: `data` should already be a tar file. If it isn't the Api will return
@@ -2379,3 +4226,104 @@ pub trait IpfsApi: Backend {
 }
 
 impl<B> IpfsApi for B where B: Backend {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_backend::MockBackend;
+
+    fn daemon() -> MockBackend {
+        MockBackend::new(|_| {
+            MockBackend::body([
+                r#"{"Name":"hello.txt","Hash":"QmWATWQ7fVPP2EFGu71UkfnqhYXDYH566qy47CnJDgvs8u","Size":"20"}"#,
+                "\n",
+            ])
+        })
+    }
+
+    #[tokio::test]
+    async fn test_add_ignores_progress() {
+        let client = daemon();
+        let add = request::Add {
+            progress: Some(true),
+            ..Default::default()
+        };
+
+        client
+            .add_with_options(Cursor::new("Hello World!"), add)
+            .await
+            .unwrap();
+
+        assert_eq!(client.requests(), ["/add?"]);
+    }
+
+    #[tokio::test]
+    async fn test_add_path_stream_keeps_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let add = request::Add {
+            preserve_mode: Some(true),
+            ..Default::default()
+        };
+
+        std::fs::write(dir.path().join("hello.txt"), "Hello World!").unwrap();
+
+        let client = daemon();
+        let res: Vec<_> = client
+            .add_path_stream(dir.path().join("hello.txt"), add, Default::default())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(client.requests(), ["/add?progress=true&preserve-mode=true"]);
+    }
+
+    #[tokio::test]
+    async fn test_add_path_ignores_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        let add = request::Add {
+            progress: Some(true),
+            ..Default::default()
+        };
+
+        std::fs::write(dir.path().join("hello.txt"), "Hello World!").unwrap();
+
+        let client = daemon();
+        let res = client
+            .add_path_with_options(dir.path().join("hello.txt"), add, Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(res.len(), 1);
+        assert_eq!(client.requests(), ["/add?"]);
+    }
+
+    #[tokio::test]
+    async fn test_routing_compat_looks_up_version_once() {
+        let client = MockBackend::new(|req| {
+            if req.starts_with("/version") {
+                MockBackend::body([r#"{"Version":"0.15.0","Commit":"","Repo":"12"}"#, "\n"])
+            } else {
+                MockBackend::body([r#"{"ID":"","Type":5,"Responses":null,"Extra":""}"#, "\n"])
+            }
+        });
+
+        for _ in 0..2 {
+            client
+                .routing_get_compat("/ipns/key")
+                .await
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            client.requests(),
+            [
+                "/version?",
+                "/dht/get?arg=%2Fipns%2Fkey",
+                "/dht/get?arg=%2Fipns%2Fkey"
+            ]
+        );
+    }
+}
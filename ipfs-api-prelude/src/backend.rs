@@ -20,7 +20,10 @@ use http::{
     StatusCode,
 };
 use serde::Deserialize;
-use std::fmt::{Debug, Display};
+use std::{
+    fmt::{Debug, Display},
+    sync::OnceLock,
+};
 use tokio_util::codec::{Decoder, FramedRead};
 
 cfg_if::cfg_if! {
@@ -242,4 +245,12 @@ pub trait Backend {
     where
         U: Into<String>,
         P: Into<String>;
+
+    /// Where the backend remembers whether the daemon serves the
+    /// `/routing/*` endpoints, once its version has been looked up. Backends
+    /// that return `None` look the version up every time it's needed.
+    ///
+    fn routing_api_cache(&self) -> Option<&OnceLock<bool>> {
+        None
+    }
 }
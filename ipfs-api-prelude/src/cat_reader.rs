@@ -0,0 +1,336 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use crate::{request, response, BoxStream, IpfsApi};
+use bytes::Bytes;
+use futures::{future, ready, AsyncRead, AsyncSeek, FutureExt, StreamExt};
+use std::{
+    io::{self, SeekFrom},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+fn to_io_error<E>(error: E) -> io::Error
+where
+    E: std::fmt::Display,
+{
+    io::Error::other(error.to_string())
+}
+
+/// Reads an Ipfs object with `AsyncRead` and `AsyncSeek`.
+///
+/// Reads are served by a single `cat` request that is only restarted, from
+/// the new offset, after a seek. Seeking from the end looks up the size of
+/// the object with `files stat`.
+///
+pub struct CatReader<'a, B>
+where
+    B: ?Sized + IpfsApi,
+{
+    client: &'a B,
+    path: String,
+    position: u64,
+    len: Option<u64>,
+    chunk: Bytes,
+    stream: Option<BoxStream<Bytes, B::Error>>,
+    stat: Option<BoxStream<response::FilesStatResponse, B::Error>>,
+}
+
+impl<'a, B> CatReader<'a, B>
+where
+    B: ?Sized + IpfsApi,
+{
+    pub(crate) fn new(client: &'a B, path: &str) -> CatReader<'a, B> {
+        CatReader {
+            client,
+            path: path.to_string(),
+            position: 0,
+            len: None,
+            chunk: Bytes::new(),
+            stream: None,
+            stat: None,
+        }
+    }
+
+    /// Returns the current position in the object.
+    ///
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn open(&self) -> BoxStream<Bytes, B::Error> {
+        let stream = self.client.cat_with_options(request::Cat {
+            path: &self.path,
+            offset: Some(self.position).filter(|offset| *offset > 0),
+            ..Default::default()
+        });
+
+        // Reads after the end keep polling the stream, which not every
+        // stream allows once it has finished.
+        //
+        Box::new(stream.fuse())
+    }
+
+    fn poll_len(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        if let Some(len) = self.len {
+            return Poll::Ready(Ok(len));
+        }
+
+        if self.stat.is_none() {
+            let path = if self.path.starts_with('/') {
+                self.path.clone()
+            } else {
+                format!("/ipfs/{}", self.path)
+            };
+            let req = request::FilesStat {
+                path: &path,
+                with_local: None,
+            };
+
+            self.stat = Some(match self.client.build_base_request(req, None) {
+                Ok(req) => self.client.request_stream_json(req),
+                Err(e) => Box::new(future::err(e).into_stream()),
+            });
+        }
+
+        let res = ready!(self.stat.as_mut().unwrap().poll_next_unpin(cx));
+
+        self.stat = None;
+
+        match res {
+            Some(Ok(stat)) => {
+                self.len = Some(stat.size);
+
+                Poll::Ready(Ok(stat.size))
+            }
+            Some(Err(e)) => Poll::Ready(Err(to_io_error(e))),
+            None => Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into())),
+        }
+    }
+}
+
+impl<'a, B> AsyncRead for CatReader<'a, B>
+where
+    B: ?Sized + IpfsApi,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        loop {
+            if !this.chunk.is_empty() {
+                let len = this.chunk.len().min(buf.len());
+
+                buf[..len].copy_from_slice(&this.chunk.split_to(len));
+                this.position += len as u64;
+
+                return Poll::Ready(Ok(len));
+            }
+
+            if matches!(this.len, Some(len) if this.position >= len) {
+                return Poll::Ready(Ok(0));
+            }
+
+            if this.stream.is_none() {
+                this.stream = Some(this.open());
+            }
+
+            match ready!(this.stream.as_mut().unwrap().poll_next_unpin(cx)) {
+                Some(Ok(chunk)) => this.chunk = chunk,
+                Some(Err(e)) => {
+                    this.stream = None;
+
+                    return Poll::Ready(Err(to_io_error(e)));
+                }
+                None => return Poll::Ready(Ok(0)),
+            }
+        }
+    }
+}
+
+impl<'a, B> AsyncSeek for CatReader<'a, B>
+where
+    B: ?Sized + IpfsApi,
+{
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(offset) => this.position.checked_add_signed(offset),
+            SeekFrom::End(offset) => ready!(this.poll_len(cx))?.checked_add_signed(offset),
+        };
+        let position = match position {
+            Some(position) => position,
+            None => {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )))
+            }
+        };
+
+        // Short seeks forward are served from the current chunk, rather than
+        // restarting the request.
+        //
+        match position.checked_sub(this.position) {
+            Some(skip) if skip <= this.chunk.len() as u64 => {
+                let _ = this.chunk.split_to(skip as usize);
+            }
+            _ => {
+                this.chunk = Bytes::new();
+                this.stream = None;
+            }
+        }
+
+        this.position = position;
+
+        Poll::Ready(Ok(position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        mock_backend::{MockBackend, MockResponse},
+        IpfsApi,
+    };
+    use futures::{future, stream, AsyncReadExt, AsyncSeekExt};
+    use std::io::SeekFrom;
+
+    const HASH: &str = "QmWATWQ7fVPP2EFGu71UkfnqhYXDYH566qy47CnJDgvs8u";
+
+    const DATA: &[u8] = b"Hello World!";
+
+    /// A daemon serving `DATA`, in chunks of 4 bytes.
+    ///
+    fn daemon() -> MockBackend {
+        MockBackend::new(|req| {
+            if req.starts_with("/files/stat") {
+                MockBackend::body([
+                    format!(
+                        r#"{{"Hash":"{}","Size":{},"CumulativeSize":20,"Blocks":0,"Type":"file"}}"#,
+                        HASH,
+                        DATA.len()
+                    ),
+                    "\n".to_string(),
+                ])
+            } else {
+                let offset = req
+                    .split_once("offset=")
+                    .map_or(0, |(_, offset)| offset.parse().unwrap());
+
+                cat(&DATA[offset..])
+            }
+        })
+    }
+
+    /// A response body that panics if it is polled again after it ends.
+    ///
+    fn cat(data: &'static [u8]) -> MockResponse {
+        Ok(Box::new(stream::unfold(data, |data| {
+            future::ready(if data.is_empty() {
+                None
+            } else {
+                let (chunk, rest) = data.split_at(data.len().min(4));
+
+                Some((Ok(chunk.into()), rest))
+            })
+        })))
+    }
+
+    #[tokio::test]
+    async fn test_short_seek_reads_from_chunk() {
+        let client = daemon();
+        let mut reader = client.cat_reader(HASH);
+        let mut buf = [0; 1];
+
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(reader.seek(SeekFrom::Current(2)).await.unwrap(), 3);
+        reader.read_exact(&mut buf).await.unwrap();
+
+        assert_eq!(&buf, b"l");
+        assert_eq!(client.requests(), [format!("/cat?arg={}", HASH)]);
+    }
+
+    #[tokio::test]
+    async fn test_long_seek_restarts_cat() {
+        let client = daemon();
+        let mut reader = client.cat_reader(HASH);
+        let mut buf = [0; 1];
+
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(reader.seek(SeekFrom::Start(6)).await.unwrap(), 6);
+
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).await.unwrap();
+
+        assert_eq!(rest, "World!");
+        assert_eq!(
+            client.requests(),
+            [
+                format!("/cat?arg={}", HASH),
+                format!("/cat?arg={}&offset=6", HASH)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_seek_from_end() {
+        let client = daemon();
+        let mut reader = client.cat_reader(HASH);
+
+        assert_eq!(reader.seek(SeekFrom::End(-6)).await.unwrap(), 6);
+
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).await.unwrap();
+
+        assert_eq!(rest, "World!");
+        assert_eq!(
+            client.requests(),
+            [
+                format!("/files/stat?arg=%2Fipfs%2F{}", HASH),
+                format!("/cat?arg={}&offset=6", HASH)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_seek_before_start() {
+        let client = daemon();
+        let mut reader = client.cat_reader(HASH);
+
+        assert!(reader.seek(SeekFrom::Current(-1)).await.is_err());
+        assert!(client.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_after_end() {
+        let client = daemon();
+        let mut reader = client.cat_reader(HASH);
+        let mut data = Vec::new();
+        let mut buf = [0; 4];
+
+        reader.read_to_end(&mut data).await.unwrap();
+
+        assert_eq!(data, DATA);
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 0);
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 0);
+        assert_eq!(client.requests().len(), 1);
+    }
+}
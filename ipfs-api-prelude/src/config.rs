@@ -0,0 +1,380 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+//! Typed model of the Kubo configuration file.
+//!
+//! Only commonly used keys are modeled. Every struct keeps the keys it
+//! doesn't know about in `extra`, so a configuration can be read, modified,
+//! and written back without losing anything. Keys that are missing or null
+//! are read as `None`, and left out when written.
+//!
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::{collections::HashMap, fmt};
+
+/// A list of strings that Kubo writes as a single string when it only has
+/// one element, such as `Addresses.API`.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Strings(pub Vec<String>);
+
+impl Serialize for Strings {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0.as_slice() {
+            [one] => serializer.serialize_str(one),
+            many => many.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Strings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StringsVisitor;
+
+        impl<'de> de::Visitor<'de> for StringsVisitor {
+            type Value = Strings;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("string or sequence of strings")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Strings(vec![s.to_string()]))
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Strings::default())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut strings = Vec::new();
+
+                while let Some(s) = seq.next_element()? {
+                    strings.push(s);
+                }
+
+                Ok(Strings(strings))
+            }
+        }
+
+        deserializer.deserialize_any(StringsVisitor)
+    }
+}
+
+/// The Kubo configuration, as returned by `config_show_typed`.
+///
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct KuboConfig {
+    #[serde(default)]
+    pub identity: Identity,
+
+    #[serde(default)]
+    pub datastore: Datastore,
+
+    #[serde(default)]
+    pub addresses: Addresses,
+
+    #[serde(default)]
+    pub discovery: Discovery,
+
+    #[serde(default)]
+    pub routing: Routing,
+
+    #[serde(default)]
+    pub ipns: Ipns,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bootstrap: Option<Vec<String>>,
+
+    #[serde(default)]
+    pub gateway: Gateway,
+
+    #[serde(default)]
+    pub swarm: Swarm,
+
+    #[serde(default)]
+    pub pubsub: Pubsub,
+
+    #[serde(default)]
+    pub experimental: Experimental,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The identity of the node. The private key is never returned by the
+/// daemon, and is kept by the daemon when the config is replaced.
+///
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Identity {
+    #[serde(rename = "PeerID", default, skip_serializing_if = "Option::is_none")]
+    pub peer_id: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Datastore {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_max: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_gc_watermark: Option<i64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gc_period: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec: Option<Map<String, Value>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_on_read: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bloom_filter_size: Option<i64>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Addresses {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swarm: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub announce: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub append_announce: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_announce: Option<Vec<String>>,
+
+    #[serde(rename = "API", default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<Strings>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<Strings>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Discovery {
+    #[serde(rename = "MDNS", default)]
+    pub mdns: Mdns,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Mdns {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Routing {
+    /// The routing mode, such as `auto`, `dht`, `dhtclient` or `none`.
+    #[serde(rename = "Type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accelerated_dht_client: Option<bool>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Ipns {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub republish_period: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_lifetime: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolve_cache_size: Option<i64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_pubsub: Option<bool>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Gateway {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<HashMap<String, Vec<String>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_redirect: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_fetch: Option<bool>,
+
+    #[serde(rename = "NoDNSLink", default, skip_serializing_if = "Option::is_none")]
+    pub no_dnslink: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_gateways: Option<Map<String, Value>>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Swarm {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub addr_filters: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_bandwidth_metrics: Option<bool>,
+
+    #[serde(
+        rename = "DisableNatPortMap",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub disable_nat_port_map: Option<bool>,
+
+    #[serde(default)]
+    pub conn_mgr: ConnMgr,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConnMgr {
+    #[serde(rename = "Type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub low_water: Option<i64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub high_water: Option<i64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grace_period: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Pubsub {
+    /// The pubsub router, `gossipsub` or `floodsub`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub router: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_signing: Option<bool>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Experimental {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filestore_enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub urlstore_enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub libp2p_stream_mounting: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p2p_http_proxy: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategic_providing: Option<bool>,
+
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = include_str!("response/tests/v0_config_show_0.json");
+
+    #[test]
+    fn test_deserializes_config() {
+        let config: KuboConfig = serde_json::from_str(CONFIG).unwrap();
+
+        assert_eq!(
+            config.identity.peer_id.as_deref(),
+            Some("12D3KooWQh2LjxNEcM9tfGU53cCFcQxwNPFCrsWMn4nYMpV8fL2S")
+        );
+        assert_eq!(
+            config.addresses.api,
+            Some(Strings(vec!["/ip4/127.0.0.1/tcp/5001".into()]))
+        );
+        assert_eq!(config.swarm.conn_mgr.high_water, Some(96));
+        assert_eq!(config.routing.kind.as_deref(), Some("auto"));
+        assert_eq!(config.swarm.addr_filters, None);
+        assert!(config.extra.contains_key("API"));
+        assert!(config.swarm.extra.contains_key("Transports"));
+    }
+
+    #[test]
+    fn test_round_trips_config() {
+        let config: KuboConfig = serde_json::from_str(CONFIG).unwrap();
+        let mut expected: Value = serde_json::from_str(CONFIG).unwrap();
+
+        // Null values are left out when written.
+        expected["Swarm"]
+            .as_object_mut()
+            .unwrap()
+            .remove("AddrFilters");
+
+        assert_eq!(serde_json::to_value(&config).unwrap(), expected);
+    }
+}
@@ -0,0 +1,601 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use bytes::Bytes;
+use futures::{
+    channel::{mpsc, oneshot},
+    executor, SinkExt, StreamExt,
+};
+use std::{
+    fs::{self, File},
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+};
+use tar::Archive;
+
+/// The number of response chunks buffered for the writer thread.
+const CHUNK_BUFFER: usize = 16;
+
+/// Progress of a `get_to_path` download.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GetProgress {
+    /// Bytes of the archive received so far.
+    pub bytes: u64,
+
+    /// Files, directories and links written so far. Always zero when the
+    /// archive is saved as-is.
+    pub entries: u64,
+}
+
+fn invalid_data<E>(error: E) -> crate::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    crate::Error::Io(io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// Writes the body of a `/get` response to disk, either by saving the
+/// archive as-is, or by extracting it.
+///
+/// File system calls block, so they are made on a separate thread, which
+/// receives the response through a bounded channel.
+///
+pub(crate) struct GetWriter {
+    chunks: Option<mpsc::Sender<Bytes>>,
+    done: oneshot::Receiver<Result<(), crate::Error>>,
+    entries: Arc<AtomicU64>,
+    progress: GetProgress,
+}
+
+impl GetWriter {
+    pub(crate) fn spawn(dest: &Path, archive: bool) -> Result<GetWriter, crate::Error> {
+        let (chunks, receiver) = mpsc::channel(CHUNK_BUFFER);
+        let (sender, done) = oneshot::channel();
+        let entries = Arc::new(AtomicU64::new(0));
+        let dest = dest.to_path_buf();
+        let counter = entries.clone();
+
+        thread::Builder::new()
+            .name("ipfs-get".into())
+            .spawn(move || {
+                let mut reader = ChunkReader {
+                    chunks: receiver,
+                    chunk: Bytes::new(),
+                };
+                let res = if archive {
+                    File::create(&dest)
+                        .and_then(|mut file| io::copy(&mut reader, &mut file))
+                        .map(|_| ())
+                        .map_err(crate::Error::Io)
+                } else {
+                    extract(&mut reader, &dest, &counter)
+                };
+
+                let _ = sender.send(res);
+            })?;
+
+        Ok(GetWriter {
+            chunks: Some(chunks),
+            done,
+            entries,
+            progress: GetProgress::default(),
+        })
+    }
+
+    pub(crate) async fn write(&mut self, chunk: Bytes) -> Result<GetProgress, crate::Error> {
+        self.progress.bytes += chunk.len() as u64;
+
+        if let Some(ref mut chunks) = self.chunks {
+            // The thread only stops reading early when it fails.
+            //
+            if chunks.send(chunk).await.is_err() {
+                self.chunks = None;
+                self.wait().await?;
+            }
+        }
+
+        self.progress.entries = self.entries.load(Ordering::Relaxed);
+
+        Ok(self.progress)
+    }
+
+    /// Waits for everything to be written, and returns the final progress.
+    ///
+    pub(crate) async fn finish(mut self) -> Result<GetProgress, crate::Error> {
+        self.chunks = None;
+        self.wait().await?;
+        self.progress.entries = self.entries.load(Ordering::Relaxed);
+
+        Ok(self.progress)
+    }
+
+    async fn wait(&mut self) -> Result<(), crate::Error> {
+        match (&mut self.done).await {
+            Ok(res) => res,
+            Err(_) => Err(crate::Error::Io(io::Error::other(
+                "get writer thread stopped unexpectedly",
+            ))),
+        }
+    }
+}
+
+/// Reads the chunks sent to the writer thread, blocking until they arrive.
+///
+struct ChunkReader {
+    chunks: mpsc::Receiver<Bytes>,
+    chunk: Bytes,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.chunk.is_empty() {
+            match executor::block_on(self.chunks.next()) {
+                Some(chunk) => self.chunk = chunk,
+                None => return Ok(0),
+            }
+        }
+
+        let len = self.chunk.len().min(buf.len());
+
+        buf[..len].copy_from_slice(&self.chunk.split_to(len));
+
+        Ok(len)
+    }
+}
+
+/// Extracts a tar archive.
+///
+/// The first component of every entry is replaced with the destination path,
+/// the same way `ipfs get -o` names its output. Entries that would be written
+/// outside of the destination, or through a symlink, are rejected. Only the
+/// permission bits of the file mode are kept.
+///
+fn extract<R>(reader: &mut R, dest: &Path, entries: &AtomicU64) -> Result<(), crate::Error>
+where
+    R: Read,
+{
+    let mut archive = Archive::new(&mut *reader);
+
+    archive.set_preserve_permissions(false);
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let kind = entry.header().entry_type();
+        let relative = relative_path(&entry.path_bytes())?;
+        let path = dest_path(dest, &relative);
+
+        if kind.is_file() || kind.is_contiguous() || kind.is_dir() {
+            create_parent(dest, &relative)?;
+            entry.unpack(&path)?;
+        } else if kind.is_symlink() {
+            let target = entry.link_name_bytes().unwrap_or_default();
+
+            check_symlink(&relative, &target)?;
+            create_parent(dest, &relative)?;
+            entry.unpack(&path)?;
+        } else if kind.is_hard_link() {
+            let source = entry.link_name_bytes().unwrap_or_default();
+            let source = relative_path(&source)?;
+
+            // Linking follows symlinks in the source's parents, which could
+            // point anywhere even when each of their targets looked safe.
+            //
+            check_no_symlinks(dest, &source)?;
+            create_parent(dest, &relative)?;
+            fs::hard_link(dest_path(dest, &source), &path)?;
+        } else {
+            // Devices and fifos are never in Ipfs archives.
+            continue;
+        }
+
+        entries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // Drain anything after the end of the archive, so the response isn't cut
+    // off.
+    //
+    io::copy(reader, &mut io::sink())?;
+
+    Ok(())
+}
+
+/// Returns the path an entry is written to. Joining an empty path would
+/// add a trailing separator, so the root entry is written to `dest`
+/// directly.
+///
+fn dest_path(dest: &Path, relative: &Path) -> PathBuf {
+    if relative.as_os_str().is_empty() {
+        dest.to_path_buf()
+    } else {
+        dest.join(relative)
+    }
+}
+
+/// Creates the parent directories of an entry, refusing to follow any
+/// symlinks inside the destination.
+///
+fn create_parent(dest: &Path, relative: &Path) -> Result<(), crate::Error> {
+    check_no_symlinks(dest, relative)?;
+
+    if let Some(parent) = dest_path(dest, relative).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that none of the components of a path inside the destination are
+/// symlinks that were already extracted.
+///
+fn check_no_symlinks(dest: &Path, relative: &Path) -> Result<(), crate::Error> {
+    let mut path = dest.to_path_buf();
+
+    for component in relative.components() {
+        path.push(component);
+
+        if let Ok(metadata) = fs::symlink_metadata(&path) {
+            if metadata.file_type().is_symlink() {
+                return Err(invalid_data(format!(
+                    "refusing to write through symlink `{}`",
+                    path.display()
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the target of a symlink stays inside the destination.
+///
+fn check_symlink(relative: &Path, target: &[u8]) -> Result<(), crate::Error> {
+    let target = String::from_utf8_lossy(target);
+    let mut depth = relative.components().count().saturating_sub(1);
+
+    for component in Path::new(target.as_ref()).components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => (),
+            Component::ParentDir if depth > 0 => depth -= 1,
+            _ => {
+                return Err(invalid_data(format!(
+                    "symlink target `{}` is outside of the destination",
+                    target
+                )))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the path of an entry relative to the destination, which replaces
+/// the first component of the path.
+///
+fn relative_path(bytes: &[u8]) -> Result<PathBuf, crate::Error> {
+    let path = String::from_utf8_lossy(bytes);
+    let mut relative = PathBuf::new();
+    let mut components = Path::new(path.as_ref())
+        .components()
+        .filter(|component| *component != Component::CurDir);
+
+    match components.next() {
+        Some(Component::Normal(_)) => (),
+        _ => return Err(invalid_data(format!("invalid path `{}` in archive", path))),
+    }
+
+    for component in components {
+        match component {
+            Component::Normal(name) => relative.push(name),
+            _ => return Err(invalid_data(format!("invalid path `{}` in archive", path))),
+        }
+    }
+
+    Ok(relative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tar::{EntryType, Header};
+
+    fn extract_chunks(
+        archive: &[u8],
+        dest: &Path,
+        chunk_size: usize,
+    ) -> Result<GetProgress, crate::Error> {
+        let mut writer = GetWriter::spawn(dest, false)?;
+
+        executor::block_on(async {
+            for chunk in archive.chunks(chunk_size) {
+                writer.write(Bytes::copy_from_slice(chunk)).await?;
+            }
+
+            writer.finish().await
+        })
+    }
+
+    fn append(builder: &mut tar::Builder<Vec<u8>>, kind: EntryType, path: &str, data: &[u8]) {
+        append_with_mode(builder, kind, path, data, 0o755);
+    }
+
+    fn append_with_mode(
+        builder: &mut tar::Builder<Vec<u8>>,
+        kind: EntryType,
+        path: &str,
+        data: &[u8],
+        mode: u32,
+    ) {
+        let mut header = Header::new_gnu();
+
+        header.set_entry_type(kind);
+        header.set_size(data.len() as u64);
+        header.set_mode(mode);
+        builder.append_data(&mut header, path, data).unwrap();
+    }
+
+    fn append_link(builder: &mut tar::Builder<Vec<u8>>, kind: EntryType, path: &str, target: &str) {
+        let mut header = Header::new_gnu();
+
+        header.set_entry_type(kind);
+        header.set_size(0);
+        builder.append_link(&mut header, path, target).unwrap();
+    }
+
+    #[test]
+    fn test_extracts_directory() {
+        let long_name = format!("root/{}/file.txt", "a".repeat(150));
+        let mut builder = tar::Builder::new(vec![]);
+
+        append(&mut builder, EntryType::Directory, "root", &[]);
+        append(&mut builder, EntryType::Directory, "root/sub", &[]);
+        append(
+            &mut builder,
+            EntryType::Regular,
+            "root/sub/hello.txt",
+            b"hello",
+        );
+        append(&mut builder, EntryType::Regular, &long_name, b"long");
+        append_link(
+            &mut builder,
+            EntryType::Link,
+            "root/hello-link.txt",
+            "root/sub/hello.txt",
+        );
+
+        let archive = builder.into_inner().unwrap();
+
+        for chunk_size in [1, 100, archive.len()] {
+            let dir = tempfile::tempdir().unwrap();
+            let dest = dir.path().join("root");
+            let progress = extract_chunks(&archive, &dest, chunk_size).unwrap();
+
+            assert_eq!(progress.entries, 5);
+            assert_eq!(progress.bytes, archive.len() as u64);
+            assert_eq!(
+                fs::read_to_string(dest.join("sub/hello.txt")).unwrap(),
+                "hello"
+            );
+            assert_eq!(
+                fs::read_to_string(dest.join("hello-link.txt")).unwrap(),
+                "hello"
+            );
+            assert!(dest.join("a".repeat(150)).join("file.txt").is_file());
+
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+
+                let metadata = fs::metadata(dest.join("sub/hello.txt")).unwrap();
+
+                assert_eq!(metadata.permissions().mode() & 0o7777, 0o755);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extracts_single_file_to_dest() {
+        let mut builder = tar::Builder::new(vec![]);
+
+        append(&mut builder, EntryType::Regular, "file.json", b"{}");
+
+        let archive = builder.into_inner().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("file.json");
+
+        extract_chunks(&archive, &dest, 512).unwrap();
+
+        assert!(dest.is_file());
+    }
+
+    #[test]
+    fn test_extracts_pax_size() {
+        let mut builder = tar::Builder::new(vec![]);
+        let mut header = Header::new_ustar();
+
+        // Go writes the size of files of 8 GiB or more in a PAX record,
+        // rather than in the header.
+        //
+        builder
+            .append_pax_extensions([("size", b"5".as_slice())])
+            .unwrap();
+        header.set_entry_type(EntryType::Regular);
+        header.set_size(0);
+        header.set_path("file.txt").unwrap();
+        header.set_cksum();
+
+        // Taken before the builder writes the end of archive marker.
+        //
+        let mut archive = builder.get_ref().clone();
+
+        archive.extend_from_slice(header.as_bytes());
+        archive.extend_from_slice(b"hello");
+        archive.extend_from_slice(&[0; 507]);
+        archive.extend_from_slice(&[0; 1024]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("file.txt");
+
+        extract_chunks(&archive, &dest, 512).unwrap();
+
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "hello");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_drops_special_mode_bits() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mut builder = tar::Builder::new(vec![]);
+
+        append_with_mode(
+            &mut builder,
+            EntryType::Regular,
+            "setuid",
+            b"#!/bin/sh",
+            0o6755,
+        );
+
+        let archive = builder.into_inner().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("setuid");
+
+        extract_chunks(&archive, &dest, 512).unwrap();
+
+        let metadata = fs::metadata(&dest).unwrap();
+
+        assert_eq!(metadata.permissions().mode() & 0o7777, 0o755);
+    }
+
+    #[test]
+    fn test_rejects_path_traversal() {
+        let mut header = Header::new_old();
+        let path = b"root/../../escape.txt";
+
+        header.as_old_mut().name[..path.len()].copy_from_slice(path);
+        header.set_size(0);
+        header.set_cksum();
+
+        let mut archive = header.as_bytes().to_vec();
+        archive.extend_from_slice(&[0; 1024]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("root");
+
+        assert!(extract_chunks(&archive, &dest, 512).is_err());
+        assert!(!dir.path().join("escape.txt").exists());
+    }
+
+    #[test]
+    fn test_rejects_hard_link_escape() {
+        let mut builder = tar::Builder::new(vec![]);
+
+        append(&mut builder, EntryType::Directory, "root", &[]);
+        append_link(&mut builder, EntryType::Link, "root/link", "/etc/passwd");
+
+        let archive = builder.into_inner().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(extract_chunks(&archive, &dir.path().join("root"), 512).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_hard_link_through_symlinks() {
+        let mut builder = tar::Builder::new(vec![]);
+
+        append(&mut builder, EntryType::Directory, "root", &[]);
+        append_link(&mut builder, EntryType::Symlink, "root/s1", ".");
+        append(&mut builder, EntryType::Directory, "root/d", &[]);
+        append_link(&mut builder, EntryType::Symlink, "root/d/s2", "../s1/..");
+        append_link(
+            &mut builder,
+            EntryType::Link,
+            "root/link",
+            "root/d/s2/secret",
+        );
+
+        let archive = builder.into_inner().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("root");
+
+        fs::write(dir.path().join("secret"), "secret").unwrap();
+
+        assert!(extract_chunks(&archive, &dest, 512).is_err());
+        assert!(!dest.join("link").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_symlink_escape() {
+        let mut builder = tar::Builder::new(vec![]);
+
+        append(&mut builder, EntryType::Directory, "root", &[]);
+        append_link(&mut builder, EntryType::Symlink, "root/link", "../..");
+
+        let archive = builder.into_inner().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(extract_chunks(&archive, &dir.path().join("root"), 512).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_writing_through_symlink() {
+        let mut builder = tar::Builder::new(vec![]);
+
+        append(&mut builder, EntryType::Directory, "root", &[]);
+        append_link(&mut builder, EntryType::Symlink, "root/link", ".");
+        append(
+            &mut builder,
+            EntryType::Regular,
+            "root/link/file.txt",
+            b"data",
+        );
+
+        let archive = builder.into_inner().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(extract_chunks(&archive, &dir.path().join("root"), 512).is_err());
+    }
+
+    #[test]
+    fn test_rejects_truncated_archive() {
+        let mut builder = tar::Builder::new(vec![]);
+
+        append(&mut builder, EntryType::Regular, "file.txt", b"hello");
+
+        let archive = builder.into_inner().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(extract_chunks(&archive[..600], &dir.path().join("file.txt"), 512).is_err());
+    }
+
+    #[test]
+    fn test_saves_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("archive.tar");
+        let mut writer = GetWriter::spawn(&dest, true).unwrap();
+        let progress = executor::block_on(async {
+            writer.write(Bytes::from_static(b"not ")).await?;
+            writer.write(Bytes::from_static(b"extracted")).await?;
+            writer.finish().await
+        })
+        .unwrap();
+
+        assert_eq!(progress.bytes, 13);
+        assert_eq!(progress.entries, 0);
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "not extracted");
+    }
+}
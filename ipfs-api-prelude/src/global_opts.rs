@@ -11,7 +11,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use common_multipart_rfc7578::client::multipart;
 use serde::{Serialize, Serializer};
-use std::time::Duration;
+use std::{sync::OnceLock, time::Duration};
 
 /// Options valid on any IPFS Api request
 ///
@@ -29,7 +29,10 @@ pub struct GlobalOptions {
     pub timeout: Option<Duration>,
 }
 
-fn duration_as_secs_ns<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+pub(crate) fn duration_as_secs_ns<S>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
@@ -85,7 +88,7 @@ impl<Back: Backend> BackendWithGlobalOptions<Back> {
         }
     }
 
-    fn combine<Req>(&self, req: Req) -> OptCombiner<Req>
+    fn combine<Req>(&self, req: Req) -> OptCombiner<'_, Req>
     where
         Req: ApiRequest,
     {
@@ -103,6 +106,8 @@ where
     const PATH: &'static str = <Req as ApiRequest>::PATH;
 
     const METHOD: http::Method = http::Method::POST;
+
+    const IDEMPOTENT: bool = <Req as ApiRequest>::IDEMPOTENT;
 }
 
 #[cfg(feature = "with-send-sync")]
@@ -122,6 +127,10 @@ impl<Back: Backend + Send + Sync> Backend for BackendWithGlobalOptions<Back> {
         (self as BackendWithGlobalOptions<Back>).with_credentials(username, password)
     }
 
+    fn routing_api_cache(&self) -> Option<&OnceLock<bool>> {
+        self.backend.routing_api_cache()
+    }
+
     fn build_base_request<Req>(
         &self,
         req: Req,
@@ -184,6 +193,10 @@ impl<Back: Backend> Backend for BackendWithGlobalOptions<Back> {
         (self as BackendWithGlobalOptions<Back>).with_credentials(username, password)
     }
 
+    fn routing_api_cache(&self) -> Option<&OnceLock<bool>> {
+        self.backend.routing_api_cache()
+    }
+
     fn build_base_request<Req>(
         &self,
         req: Req,
@@ -228,3 +241,27 @@ impl<Back: Backend> Backend for BackendWithGlobalOptions<Back> {
         self.backend.request_stream(req, process)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request;
+
+    fn is_idempotent<Req: ApiRequest>(_req: &Req) -> bool {
+        Req::IDEMPOTENT
+    }
+
+    #[test]
+    fn test_combined_request_keeps_idempotence() {
+        let global = GlobalOptions::default();
+
+        assert!(is_idempotent(&OptCombiner {
+            global: &global,
+            request: request::Version,
+        }));
+        assert!(!is_idempotent(&OptCombiner {
+            global: &global,
+            request: request::Shutdown,
+        }));
+    }
+}
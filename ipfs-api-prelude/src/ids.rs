@@ -0,0 +1,69 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+//! Types of the content, peer and address identifiers found in responses.
+//!
+//! With the `with-typed-ids` feature these are parsed into [`cid::Cid`],
+//! [`libp2p_identity::PeerId`] and [`multiaddr::Multiaddr`]. Without it, they
+//! are left as `String`s.
+//!
+
+use serde::Serializer;
+use std::fmt::Display;
+
+/// The multicodec of a CID that holds a peer ID, such as the base36 key IDs
+/// returned by newer daemons.
+///
+#[cfg(feature = "with-typed-ids")]
+const LIBP2P_KEY: u64 = 0x72;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "with-typed-ids")] {
+        pub use cid;
+        pub use libp2p_identity;
+
+        pub type ContentId = cid::Cid;
+        pub type PeerId = libp2p_identity::PeerId;
+        pub type Multiaddr = multiaddr::Multiaddr;
+    } else {
+        pub type ContentId = String;
+        pub type PeerId = String;
+        pub type Multiaddr = String;
+    }
+}
+
+/// Parses a peer ID, either in its base58 form or as a `libp2p-key` CID.
+///
+#[cfg(feature = "with-typed-ids")]
+pub(crate) fn parse_peer_id(s: &str) -> Result<PeerId, String> {
+    match s.parse() {
+        Ok(peer_id) => Ok(peer_id),
+        Err(e) => match s.parse::<ContentId>() {
+            Ok(cid) if cid.codec() == LIBP2P_KEY => {
+                PeerId::from_multihash(*cid.hash()).map_err(|_| e.to_string())
+            }
+            _ => Err(e.to_string()),
+        },
+    }
+}
+
+#[cfg(not(feature = "with-typed-ids"))]
+pub(crate) fn parse_peer_id(s: &str) -> Result<PeerId, String> {
+    Ok(s.to_string())
+}
+
+/// Serializes a request argument using its `Display` implementation, so
+/// request structs can take typed identifiers as well as `str`.
+///
+pub(crate) fn serialize_display<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
@@ -12,22 +12,36 @@ extern crate typed_builder;
 
 extern crate serde;
 
+mod add_path;
 mod api;
 mod backend;
+mod cat_reader;
+pub mod config;
 mod error;
 mod from_uri;
+mod get_path;
 mod global_opts;
 mod header;
+pub mod ids;
+#[cfg(test)]
+mod mock_backend;
 mod read;
 pub mod request;
 pub mod response;
+mod retry;
+mod subscription;
 
 pub use {
+    add_path::{AddPathFilter, SymlinkPolicy},
     api::IpfsApi,
     backend::{Backend, BoxStream},
+    cat_reader::CatReader,
     error::Error,
     from_uri::TryFromUri,
+    get_path::GetProgress,
     global_opts::{BackendWithGlobalOptions, GlobalOptions},
     request::ApiRequest,
     response::ApiError,
+    retry::{ErrorClass, RetryPolicy},
+    subscription::{ResilientSubscription, SubscriptionEvent},
 };
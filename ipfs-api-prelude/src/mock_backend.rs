@@ -0,0 +1,171 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+//! A `Backend` that answers requests with canned responses, to test the
+//! client side helpers without a daemon.
+//!
+
+use crate::{ApiError, ApiRequest, Backend, BoxStream};
+use async_trait::async_trait;
+use bytes::Bytes;
+use common_multipart_rfc7578::client::multipart;
+use futures::{future, stream, TryStreamExt};
+use http::{
+    header::{HeaderName, HeaderValue},
+    StatusCode,
+};
+use std::sync::{Mutex, OnceLock};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MockError {
+    #[error("api returned error `{0}`")]
+    Api(ApiError),
+
+    #[error("ipfs client error `{0}`")]
+    Client(#[from] crate::Error),
+
+    #[error("connection lost")]
+    Disconnected,
+}
+
+impl From<ApiError> for MockError {
+    fn from(err: ApiError) -> Self {
+        MockError::Api(err)
+    }
+}
+
+pub type MockResponse = Result<BoxStream<Bytes, MockError>, MockError>;
+
+type Responder = Box<dyn FnMut(&str) -> MockResponse + Send>;
+
+/// Answers each request with the response returned by a closure, called with
+/// the path and query of the request.
+///
+pub struct MockBackend {
+    responder: Mutex<Responder>,
+    requests: Mutex<Vec<String>>,
+    routing_api: OnceLock<bool>,
+}
+
+impl MockBackend {
+    pub fn new<F>(responder: F) -> MockBackend
+    where
+        F: 'static + FnMut(&str) -> MockResponse + Send,
+    {
+        MockBackend {
+            responder: Mutex::new(Box::new(responder)),
+            requests: Mutex::new(Vec::new()),
+            routing_api: OnceLock::new(),
+        }
+    }
+
+    /// A response with the given body chunks.
+    ///
+    pub fn body<I, T>(chunks: I) -> MockResponse
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Bytes>,
+    {
+        let chunks: Vec<_> = chunks.into_iter().map(|chunk| Ok(chunk.into())).collect();
+
+        Ok(Box::new(stream::iter(chunks)))
+    }
+
+    /// A response whose body never ends.
+    ///
+    pub fn pending() -> MockResponse {
+        Ok(Box::new(stream::pending()))
+    }
+
+    /// Returns the path and query of every request made so far.
+    ///
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, req: &str) -> MockResponse {
+        self.requests.lock().unwrap().push(req.to_string());
+
+        (self.responder.lock().unwrap())(req)
+    }
+}
+
+#[cfg_attr(feature = "with-send-sync", async_trait)]
+#[cfg_attr(not(feature = "with-send-sync"), async_trait(?Send))]
+impl Backend for MockBackend {
+    type HttpRequest = String;
+
+    type HttpResponse = BoxStream<Bytes, MockError>;
+
+    type Error = MockError;
+
+    fn build_base_request<Req>(
+        &self,
+        req: Req,
+        _form: Option<multipart::Form<'static>>,
+    ) -> Result<Self::HttpRequest, Self::Error>
+    where
+        Req: ApiRequest,
+    {
+        let query = serde_urlencoded::to_string(req).map_err(crate::Error::from)?;
+
+        Ok(format!("{}?{}", Req::PATH, query))
+    }
+
+    fn get_header(_res: &Self::HttpResponse, _key: HeaderName) -> Option<&HeaderValue> {
+        None
+    }
+
+    async fn request_raw<Req>(
+        &self,
+        req: Req,
+        form: Option<multipart::Form<'static>>,
+    ) -> Result<(StatusCode, Bytes), Self::Error>
+    where
+        Req: ApiRequest,
+    {
+        let req = self.build_base_request(req, form)?;
+        let body: Vec<Bytes> = self.respond(&req)?.try_collect().await?;
+
+        Ok((StatusCode::OK, body.concat().into()))
+    }
+
+    fn response_to_byte_stream(res: Self::HttpResponse) -> BoxStream<Bytes, Self::Error> {
+        res
+    }
+
+    fn request_stream<Res, F>(
+        &self,
+        req: Self::HttpRequest,
+        process: F,
+    ) -> BoxStream<Res, Self::Error>
+    where
+        F: 'static + Send + Fn(Self::HttpResponse) -> BoxStream<Res, Self::Error>,
+    {
+        // Errors are sent as the body, since there is no other way to build
+        // a stream of `Res` here.
+        //
+        match self.respond(&req) {
+            Ok(res) => process(res),
+            Err(e) => process(Box::new(stream::once(future::err(e)))),
+        }
+    }
+
+    fn with_credentials<U, P>(self, _username: U, _password: P) -> Self
+    where
+        U: Into<String>,
+        P: Into<String>,
+    {
+        self
+    }
+
+    fn routing_api_cache(&self) -> Option<&OnceLock<bool>> {
+        Some(&self.routing_api)
+    }
+}
@@ -206,9 +206,7 @@ where
 
                         Poll::Ready(Ok(()))
                     }
-                    Some(Err(e)) => {
-                        Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e.to_string())))
-                    }
+                    Some(Err(e)) => Poll::Ready(Err(io::Error::other(e.to_string()))),
                     // Polling stream yielded EOF.
                     //
                     None => Poll::Ready(Ok(())),
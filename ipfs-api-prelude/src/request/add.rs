@@ -58,8 +58,63 @@ pub struct Add<'a> {
     ///  Add reference to Files API (MFS) at the provided path
     #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
     pub to_files: Option<&'a str>,
+
+    /// Stream progress data. Progress events can only be read with
+    /// `add_stream` or `add_path_stream`, and the other `add` methods
+    /// ignore this option.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub progress: Option<bool>,
+
+    /// Store the file mode of added files. When adding a path, the mode of
+    /// each file is read and sent along with it.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub preserve_mode: Option<bool>,
+
+    /// Store the modification time of added files. When adding a path, the
+    /// modification time of each file is read and sent along with it.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub preserve_mtime: Option<bool>,
+
+    /// Custom POSIX file mode to store in created UnixFS entries.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub mode: Option<u32>,
+
+    /// Custom modification time to store in created UnixFS entries, in
+    /// seconds since the Unix epoch.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub mtime: Option<i64>,
+
+    /// Nanosecond fraction of `mtime`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub mtime_nsecs: Option<u32>,
 }
 
 impl<'a> ApiRequest for Add<'a> {
     const PATH: &'static str = "/add";
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_preserve,
+        Add {
+            preserve_mode: Some(true),
+            preserve_mtime: Some(true),
+            ..Default::default()
+        },
+        "preserve-mode=true&preserve-mtime=true"
+    );
+
+    serialize_url_test!(
+        test_serializes_mtime,
+        Add {
+            mode: Some(0o755),
+            mtime: Some(1_600_000_000),
+            mtime_nsecs: Some(500),
+            ..Default::default()
+        },
+        "mode=493&mtime=1600000000&mtime-nsecs=500"
+    );
+}
@@ -17,6 +17,8 @@ pub struct BitswapLedger<'a> {
 
 impl<'a> ApiRequest for BitswapLedger<'a> {
     const PATH: &'static str = "/bitswap/ledger";
+
+    const IDEMPOTENT: bool = true;
 }
 
 pub struct BitswapReprovide;
@@ -33,6 +35,8 @@ impl_skip_serialize!(BitswapStat);
 
 impl ApiRequest for BitswapStat {
     const PATH: &'static str = "/bitswap/stat";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -42,7 +46,7 @@ pub struct BitswapUnwant<'a> {
 }
 
 impl<'a> ApiRequest for BitswapUnwant<'a> {
-    const PATH: &'static str = "/bitswap/stat";
+    const PATH: &'static str = "/bitswap/unwant";
 }
 
 #[derive(Serialize)]
@@ -52,4 +56,6 @@ pub struct BitswapWantlist<'a> {
 
 impl<'a> ApiRequest for BitswapWantlist<'a> {
     const PATH: &'static str = "/bitswap/wantlist";
+
+    const IDEMPOTENT: bool = true;
 }
@@ -8,15 +8,19 @@
 
 use crate::request::ApiRequest;
 use serde::Serialize;
+use std::fmt::Display;
 
 #[derive(Serialize)]
-pub struct BlockGet<'a> {
-    #[serde(rename = "arg")]
-    pub hash: &'a str,
+#[serde(bound = "A: Display")]
+pub struct BlockGet<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub hash: &'a A,
 }
 
-impl<'a> ApiRequest for BlockGet<'a> {
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for BlockGet<'a, A> {
     const PATH: &'static str = "/block/get";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
@@ -48,11 +52,14 @@ impl<'a> ApiRequest for BlockRm<'a> {
 }
 
 #[derive(Serialize)]
-pub struct BlockStat<'a> {
-    #[serde(rename = "arg")]
-    pub hash: &'a str,
+#[serde(bound = "A: Display")]
+pub struct BlockStat<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub hash: &'a A,
 }
 
-impl<'a> ApiRequest for BlockStat<'a> {
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for BlockStat<'a, A> {
     const PATH: &'static str = "/block/stat";
+
+    const IDEMPOTENT: bool = true;
 }
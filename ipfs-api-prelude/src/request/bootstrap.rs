@@ -22,6 +22,8 @@ impl_skip_serialize!(BootstrapList);
 
 impl ApiRequest for BootstrapList {
     const PATH: &'static str = "/bootstrap/list";
+
+    const IDEMPOTENT: bool = true;
 }
 
 pub struct BootstrapRmAll;
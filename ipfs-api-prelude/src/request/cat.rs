@@ -9,15 +9,54 @@
 use crate::request::ApiRequest;
 use serde::Serialize;
 
-#[derive(Serialize)]
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
 pub struct Cat<'a> {
+    /// The path to the IPFS object to be outputted.
     #[serde(rename = "arg")]
     pub path: &'a str,
 
-    pub offset: Option<usize>,
-    pub length: Option<usize>,
+    /// Byte offset to begin reading from.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub offset: Option<u64>,
+
+    /// Maximum number of bytes to read. Reads to the end of the object if
+    /// not set.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub length: Option<u64>,
+
+    /// Stream progress data.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub progress: Option<bool>,
 }
 
 impl<'a> ApiRequest for Cat<'a> {
     const PATH: &'static str = "/cat";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_0,
+        Cat {
+            path: "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA",
+            ..Default::default()
+        },
+        "arg=QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA"
+    );
+
+    serialize_url_test!(
+        test_serializes_1,
+        Cat {
+            path: "QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA",
+            offset: Some(5_000_000_000),
+            length: Some(1024),
+            progress: Some(false),
+        },
+        "arg=QmXdNSQx7nbdRvkjGCEQgVjVtVwsHvV8NmV2a8xzQVwuFA&offset=5000000000&length=1024&progress=false"
+    );
 }
@@ -14,4 +14,6 @@ impl_skip_serialize!(Commands);
 
 impl ApiRequest for Commands {
     const PATH: &'static str = "/commands";
+
+    const IDEMPOTENT: bool = true;
 }
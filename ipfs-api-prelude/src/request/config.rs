@@ -36,6 +36,21 @@ impl ApiRequest for ConfigEdit {
     const PATH: &'static str = "/config/edit";
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ConfigProfileApply<'a> {
+    /// The name of the profile, such as `server` or `lowpower`.
+    #[serde(rename = "arg")]
+    pub profile: &'a str,
+
+    /// Return the new config without applying it.
+    pub dry_run: bool,
+}
+
+impl<'a> ApiRequest for ConfigProfileApply<'a> {
+    const PATH: &'static str = "/config/profile/apply";
+}
+
 pub struct ConfigReplace;
 
 impl_skip_serialize!(ConfigReplace);
@@ -50,4 +65,20 @@ impl_skip_serialize!(ConfigShow);
 
 impl ApiRequest for ConfigShow {
     const PATH: &'static str = "/config/show";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_profile_apply,
+        ConfigProfileApply {
+            profile: "lowpower",
+            dry_run: true,
+        },
+        "arg=lowpower&dry-run=true"
+    );
 }
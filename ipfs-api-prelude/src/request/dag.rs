@@ -31,6 +31,8 @@ pub struct DagGet<'a> {
 
 impl<'a> ApiRequest for DagGet<'a> {
     const PATH: &'static str = "/dag/get";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
@@ -55,6 +57,66 @@ impl ApiRequest for DagPut<'_> {
     const PATH: &'static str = "/dag/put";
 }
 
+#[derive(Serialize)]
+pub struct DagExport<'a> {
+    #[serde(rename = "arg")]
+    pub root: &'a str,
+}
+
+impl ApiRequest for DagExport<'_> {
+    const PATH: &'static str = "/dag/export";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct DagImport {
+    /// Pin optional roots listed in the CAR headers after importing. Default: true. Required: no.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub pin_roots: Option<bool>,
+    /// No output. Required: no.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub silent: Option<bool>,
+    /// Output stats. Required: no.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub stats: Option<bool>,
+}
+
+impl ApiRequest for DagImport {
+    const PATH: &'static str = "/dag/import";
+}
+
+#[derive(Serialize)]
+pub struct DagResolve<'a> {
+    #[serde(rename = "arg")]
+    pub path: &'a str,
+}
+
+impl ApiRequest for DagResolve<'_> {
+    const PATH: &'static str = "/dag/resolve";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+pub struct DagStat<'a> {
+    #[serde(rename = "arg")]
+    pub path: &'a str,
+
+    /// Return progressive data while reading through the DAG. Default: true. Required: no.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub progress: Option<bool>,
+}
+
+impl ApiRequest for DagStat<'_> {
+    const PATH: &'static str = "/dag/stat";
+
+    const IDEMPOTENT: bool = true;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +157,39 @@ mod tests {
         },
         "store-codec=dag-json&input-codec=dag-cbor&pin=false&hash=sha3_384"
     );
+
+    serialize_url_test!(
+        test_serializes_dag_export,
+        DagExport {
+            root: "bafkreiadxiqe4ugre3sgotaalycnqlueyijwm6ak6h2dxvkkg6aww2vtia",
+        },
+        "arg=bafkreiadxiqe4ugre3sgotaalycnqlueyijwm6ak6h2dxvkkg6aww2vtia"
+    );
+
+    serialize_url_test!(
+        test_serializes_dag_import_with_options,
+        DagImport {
+            pin_roots: Some(false),
+            silent: Some(false),
+            stats: Some(true),
+        },
+        "pin-roots=false&silent=false&stats=true"
+    );
+
+    serialize_url_test!(
+        test_serializes_dag_resolve,
+        DagResolve {
+            path: "bafyreia4gfnyxbg6bzrj4pdqoxsrtoc6mxvrhvdq6bt6qrfwlfpxcwf4ju/a/b",
+        },
+        "arg=bafyreia4gfnyxbg6bzrj4pdqoxsrtoc6mxvrhvdq6bt6qrfwlfpxcwf4ju%2Fa%2Fb"
+    );
+
+    serialize_url_test!(
+        test_serializes_dag_stat_with_options,
+        DagStat {
+            path: "bafyreia4gfnyxbg6bzrj4pdqoxsrtoc6mxvrhvdq6bt6qrfwlfpxcwf4ju",
+            progress: Some(false),
+        },
+        "arg=bafyreia4gfnyxbg6bzrj4pdqoxsrtoc6mxvrhvdq6bt6qrfwlfpxcwf4ju&progress=false"
+    );
 }
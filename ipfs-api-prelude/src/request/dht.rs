@@ -8,24 +8,27 @@
 
 use crate::request::ApiRequest;
 use serde::Serialize;
+use std::fmt::Display;
 
 #[derive(Serialize)]
-pub struct DhtFindPeer<'a> {
-    #[serde(rename = "arg")]
-    pub peer: &'a str,
+#[serde(bound = "A: Display")]
+pub struct DhtFindPeer<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub peer: &'a A,
 }
 
-impl<'a> ApiRequest for DhtFindPeer<'a> {
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for DhtFindPeer<'a, A> {
     const PATH: &'static str = "/dht/findpeer";
 }
 
 #[derive(Serialize)]
-pub struct DhtFindProvs<'a> {
-    #[serde(rename = "arg")]
-    pub key: &'a str,
+#[serde(bound = "A: Display")]
+pub struct DhtFindProvs<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub key: &'a A,
 }
 
-impl<'a> ApiRequest for DhtFindProvs<'a> {
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for DhtFindProvs<'a, A> {
     const PATH: &'static str = "/dht/findprovs";
 }
 
@@ -40,12 +43,13 @@ impl<'a> ApiRequest for DhtGet<'a> {
 }
 
 #[derive(Serialize)]
-pub struct DhtProvide<'a> {
-    #[serde(rename = "arg")]
-    pub key: &'a str,
+#[serde(bound = "A: Display")]
+pub struct DhtProvide<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub key: &'a A,
 }
 
-impl<'a> ApiRequest for DhtProvide<'a> {
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for DhtProvide<'a, A> {
     const PATH: &'static str = "/dht/provide";
 }
 
@@ -63,11 +67,12 @@ impl<'a> ApiRequest for DhtPut<'a> {
 }
 
 #[derive(Serialize)]
-pub struct DhtQuery<'a> {
-    #[serde(rename = "arg")]
-    pub peer: &'a str,
+#[serde(bound = "A: Display")]
+pub struct DhtQuery<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub peer: &'a A,
 }
 
-impl<'a> ApiRequest for DhtQuery<'a> {
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for DhtQuery<'a, A> {
     const PATH: &'static str = "/dht/query";
 }
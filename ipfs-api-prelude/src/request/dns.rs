@@ -19,4 +19,6 @@ pub struct Dns<'a> {
 
 impl<'a> ApiRequest for Dns<'a> {
     const PATH: &'static str = "/dns";
+
+    const IDEMPOTENT: bool = true;
 }
@@ -17,4 +17,6 @@ pub struct FileLs<'a> {
 
 impl<'a> ApiRequest for FileLs<'a> {
     const PATH: &'static str = "/file/ls";
+
+    const IDEMPOTENT: bool = true;
 }
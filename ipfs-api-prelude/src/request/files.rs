@@ -51,6 +51,8 @@ pub struct FilesLs<'a> {
 
 impl<'a> ApiRequest for FilesLs<'a> {
     const PATH: &'static str = "/files/ls";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
@@ -107,6 +109,8 @@ pub struct FilesRead<'a> {
 
 impl<'a> ApiRequest for FilesRead<'a> {
     const PATH: &'static str = "/files/read";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
@@ -137,6 +141,8 @@ pub struct FilesStat<'a> {
 
 impl<'a> ApiRequest for FilesStat<'a> {
     const PATH: &'static str = "/files/stat";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
@@ -15,6 +15,8 @@ impl_skip_serialize!(FilestoreDups);
 
 impl ApiRequest for FilestoreDups {
     const PATH: &'static str = "/filestore/dups";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -25,6 +27,8 @@ pub struct FilestoreLs<'a> {
 
 impl<'a> ApiRequest for FilestoreLs<'a> {
     const PATH: &'static str = "/filestore/ls";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -9,12 +9,54 @@
 use crate::request::ApiRequest;
 use serde::Serialize;
 
-#[derive(Serialize)]
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
 pub struct Get<'a> {
+    /// The path to the IPFS object to be outputted.
     #[serde(rename = "arg")]
     pub path: &'a str,
+
+    /// Output a TAR archive.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub archive: Option<bool>,
+
+    /// Compress the output with GZIP compression.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub compress: Option<bool>,
+
+    /// The level of compression (1-9).
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub compression_level: Option<i32>,
 }
 
 impl<'a> ApiRequest for Get<'a> {
     const PATH: &'static str = "/get";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_0,
+        Get {
+            path: "QmXfeBsVFdbMVAgZmWANgvZadmNmwVELQDuwtiqiAYVi1j",
+            ..Default::default()
+        },
+        "arg=QmXfeBsVFdbMVAgZmWANgvZadmNmwVELQDuwtiqiAYVi1j"
+    );
+
+    serialize_url_test!(
+        test_serializes_1,
+        Get {
+            path: "QmXfeBsVFdbMVAgZmWANgvZadmNmwVELQDuwtiqiAYVi1j",
+            archive: Some(true),
+            compress: Some(true),
+            compression_level: Some(6),
+        },
+        "arg=QmXfeBsVFdbMVAgZmWANgvZadmNmwVELQDuwtiqiAYVi1j&archive=true&compress=true&compression-level=6"
+    );
 }
@@ -17,4 +17,6 @@ pub struct Id<'a> {
 
 impl<'a> ApiRequest for Id<'a> {
     const PATH: &'static str = "/id";
+
+    const IDEMPOTENT: bool = true;
 }
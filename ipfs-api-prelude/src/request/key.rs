@@ -29,6 +29,52 @@ impl Serialize for KeyType {
     }
 }
 
+impl KeyType {
+    /// Returns the size to send for this key type.
+    ///
+    /// Ed25519 keys have a fixed size, and the daemon rejects requests
+    /// that specify one, so the size is dropped for them.
+    pub fn key_size(self, size: i32) -> Option<i32> {
+        match self {
+            KeyType::Rsa => Some(size),
+            KeyType::Ed25519 => None,
+        }
+    }
+}
+
+/// Encoding used when exporting or importing a key.
+#[derive(Copy, Clone)]
+pub enum KeyFormat {
+    Libp2pProtobufCleartext,
+    PemPkcs8Cleartext,
+}
+
+impl Serialize for KeyFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let s = match self {
+            KeyFormat::Libp2pProtobufCleartext => "libp2p-protobuf-cleartext",
+            KeyFormat::PemPkcs8Cleartext => "pem-pkcs8-cleartext",
+        };
+
+        serializer.serialize_str(s)
+    }
+}
+
+#[derive(Serialize)]
+pub struct KeyExport<'a> {
+    #[serde(rename = "arg")]
+    pub name: &'a str,
+
+    pub format: Option<KeyFormat>,
+}
+
+impl<'a> ApiRequest for KeyExport<'a> {
+    const PATH: &'static str = "/key/export";
+}
+
 #[derive(Serialize)]
 pub struct KeyGen<'a> {
     #[serde(rename = "arg")]
@@ -37,19 +83,37 @@ pub struct KeyGen<'a> {
     #[serde(rename = "type")]
     pub kind: KeyType,
 
-    pub size: i32,
+    /// Must be `None` for `KeyType::Ed25519`.
+    pub size: Option<i32>,
 }
 
 impl<'a> ApiRequest for KeyGen<'a> {
     const PATH: &'static str = "/key/gen";
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct KeyImport<'a> {
+    #[serde(rename = "arg")]
+    pub name: &'a str,
+
+    pub format: Option<KeyFormat>,
+
+    pub allow_any_key_type: Option<bool>,
+}
+
+impl<'a> ApiRequest for KeyImport<'a> {
+    const PATH: &'static str = "/key/import";
+}
+
 pub struct KeyList;
 
 impl_skip_serialize!(KeyList);
 
 impl ApiRequest for KeyList {
     const PATH: &'static str = "/key/list";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -76,3 +140,78 @@ pub struct KeyRm<'a> {
 impl<'a> ApiRequest for KeyRm<'a> {
     const PATH: &'static str = "/key/rm";
 }
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+pub struct KeyRotate<'a> {
+    /// Keystore name under which to keep the old key.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub oldkey: Option<&'a str>,
+
+    /// Type of the new key.
+    #[serde(rename = "type")]
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub kind: Option<KeyType>,
+
+    /// Size of the new key. Only valid for `KeyType::Rsa`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub size: Option<i32>,
+}
+
+impl<'a> ApiRequest for KeyRotate<'a> {
+    const PATH: &'static str = "/key/rotate";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_key_gen_ed25519,
+        KeyGen {
+            name: "test",
+            kind: KeyType::Ed25519,
+            size: KeyType::Ed25519.key_size(2048),
+        },
+        "arg=test&type=ed25519"
+    );
+
+    serialize_url_test!(
+        test_serializes_key_gen_rsa,
+        KeyGen {
+            name: "test",
+            kind: KeyType::Rsa,
+            size: KeyType::Rsa.key_size(2048),
+        },
+        "arg=test&type=rsa&size=2048"
+    );
+
+    serialize_url_test!(
+        test_serializes_key_export,
+        KeyExport {
+            name: "test",
+            format: Some(KeyFormat::PemPkcs8Cleartext),
+        },
+        "arg=test&format=pem-pkcs8-cleartext"
+    );
+
+    serialize_url_test!(
+        test_serializes_key_import,
+        KeyImport {
+            name: "test",
+            format: Some(KeyFormat::Libp2pProtobufCleartext),
+            allow_any_key_type: Some(true),
+        },
+        "arg=test&format=libp2p-protobuf-cleartext&allow-any-key-type=true"
+    );
+
+    serialize_url_test!(
+        test_serializes_key_rotate,
+        KeyRotate {
+            oldkey: Some("old"),
+            kind: Some(KeyType::Rsa),
+            size: Some(2048),
+        },
+        "oldkey=old&type=rsa&size=2048"
+    );
+}
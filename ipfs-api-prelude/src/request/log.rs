@@ -74,6 +74,8 @@ impl_skip_serialize!(LogLs);
 
 impl ApiRequest for LogLs {
     const PATH: &'static str = "/log/ls";
+
+    const IDEMPOTENT: bool = true;
 }
 
 pub struct LogTail;
@@ -28,6 +28,8 @@ pub struct Ls<'a> {
 
 impl<'a> ApiRequest for Ls<'a> {
     const PATH: &'static str = "/ls";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[cfg(test)]
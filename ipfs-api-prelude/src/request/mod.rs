@@ -31,6 +31,9 @@ pub use self::pin::*;
 pub use self::ping::*;
 pub use self::pubsub::*;
 pub use self::refs::*;
+pub use self::repo::*;
+pub use self::resolve::*;
+pub use self::routing::*;
 pub use self::shutdown::*;
 pub use self::stats::*;
 pub use self::swarm::*;
@@ -93,6 +96,9 @@ mod pin;
 mod ping;
 mod pubsub;
 mod refs;
+mod repo;
+mod resolve;
+mod routing;
 mod shutdown;
 mod stats;
 mod swarm;
@@ -116,6 +122,12 @@ pub trait ApiRequest: Serialize + Send {
     ///
     const METHOD: http::Method = http::Method::POST;
 
+    /// Whether the request can safely be sent again, if it may or may not
+    /// have reached the daemon. Only idempotent requests are retried by a
+    /// `RetryPolicy`.
+    ///
+    const IDEMPOTENT: bool = false;
+
     /// Creates the absolute URL for an API resource given the base path
     /// of the service.
     ///
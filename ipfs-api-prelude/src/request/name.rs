@@ -8,25 +8,101 @@
 
 use crate::request::ApiRequest;
 use serde::Serialize;
+use std::time::Duration;
 
 #[derive(Serialize)]
-pub struct NamePublish<'a, 'b, 'c, 'd> {
+pub struct NameInspect<'a> {
+    /// CID of the public IPNS key to validate the record against.
+    pub verify: Option<&'a str>,
+
+    /// Include a hex dump of the record.
+    pub dump: Option<bool>,
+}
+
+impl<'a> ApiRequest for NameInspect<'a> {
+    const PATH: &'static str = "/name/inspect";
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct NamePublish<'a> {
+    /// IPFS path of the object to be published.
     #[serde(rename = "arg")]
     pub path: &'a str,
 
-    pub resolve: bool,
+    /// Check if the given path can be resolved before publishing. Defaults to `true`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub resolve: Option<bool>,
+
+    /// Time duration that the record will be valid for. Defaults to 24 hours.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    #[serde(serialize_with = "crate::global_opts::duration_as_secs_ns")]
+    pub lifetime: Option<Duration>,
+
+    /// When offline, save the IPNS record to the local datastore without
+    /// broadcasting to the network instead of simply failing.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub allow_offline: Option<bool>,
+
+    /// Time duration this record should be cached for.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    #[serde(serialize_with = "crate::global_opts::duration_as_secs_ns")]
+    pub ttl: Option<Duration>,
 
-    pub lifetime: Option<&'b str>,
+    /// Name of the key to be used, as listed by `key_list`. Defaults to `self`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub key: Option<&'a str>,
 
-    pub ttl: Option<&'c str>,
+    /// Write only final hash.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub quieter: Option<bool>,
 
-    pub key: Option<&'d str>,
+    /// Encoding used for keys. Can either be a multibase encoded CID or a
+    /// base58btc encoded multihash. Defaults to `base36`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub ipns_base: Option<&'a str>,
+
+    /// Produce a backward-compatible record that also carries a V1
+    /// signature. Defaults to `true`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub v1compat: Option<bool>,
 }
 
-impl<'a, 'b, 'c, 'd> ApiRequest for NamePublish<'a, 'b, 'c, 'd> {
+impl<'a> ApiRequest for NamePublish<'a> {
     const PATH: &'static str = "/name/publish";
 }
 
+#[derive(Serialize)]
+pub struct NamePubsubCancel<'a> {
+    #[serde(rename = "arg")]
+    pub name: &'a str,
+}
+
+impl<'a> ApiRequest for NamePubsubCancel<'a> {
+    const PATH: &'static str = "/name/pubsub/cancel";
+}
+
+pub struct NamePubsubState;
+
+impl_skip_serialize!(NamePubsubState);
+
+impl ApiRequest for NamePubsubState {
+    const PATH: &'static str = "/name/pubsub/state";
+
+    const IDEMPOTENT: bool = true;
+}
+
+pub struct NamePubsubSubs;
+
+impl_skip_serialize!(NamePubsubSubs);
+
+impl ApiRequest for NamePubsubSubs {
+    const PATH: &'static str = "/name/pubsub/subs";
+
+    const IDEMPOTENT: bool = true;
+}
+
 #[derive(Serialize)]
 pub struct NameResolve<'a> {
     #[serde(rename = "arg")]
@@ -39,4 +115,55 @@ pub struct NameResolve<'a> {
 
 impl<'a> ApiRequest for NameResolve<'a> {
     const PATH: &'static str = "/name/resolve";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_name_inspect,
+        NameInspect {
+            verify: Some("k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8"),
+            dump: Some(false),
+        },
+        "verify=k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8&dump=false"
+    );
+
+    serialize_url_test!(
+        test_serializes_name_publish,
+        NamePublish {
+            path: "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
+            ..Default::default()
+        },
+        "arg=%2Fipfs%2FQmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY"
+    );
+
+    serialize_url_test!(
+        test_serializes_name_publish_with_options,
+        NamePublish {
+            path: "/ipfs/QmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY",
+            resolve: Some(false),
+            lifetime: Some(Duration::from_secs(24 * 60 * 60)),
+            allow_offline: Some(true),
+            ttl: Some(Duration::from_millis(1500)),
+            key: Some("publisher"),
+            quieter: Some(true),
+            ipns_base: Some("base58btc"),
+            v1compat: Some(false),
+        },
+        "arg=%2Fipfs%2FQmVrLsEDn27sScp3k23sgZNefVTjSAL3wpgW1iWPi4MgoY&resolve=false\
+         &lifetime=86400s0ns&allow-offline=true&ttl=1s500000000ns&key=publisher\
+         &quieter=true&ipns-base=base58btc&v1compat=false"
+    );
+
+    serialize_url_test!(
+        test_serializes_name_pubsub_cancel,
+        NamePubsubCancel {
+            name: "/ipns/k51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8"
+        },
+        "arg=%2Fipns%2Fk51qzi5uqu5dlvj2baxnqndepeb86cbk3ng7n3i46uzyxzyqj2xjonzllnv0v8"
+    );
 }
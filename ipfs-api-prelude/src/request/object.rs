@@ -17,6 +17,8 @@ pub struct ObjectData<'a> {
 
 impl<'a> ApiRequest for ObjectData<'a> {
     const PATH: &'static str = "/object/data";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -30,6 +32,8 @@ pub struct ObjectDiff<'a> {
 
 impl<'a> ApiRequest for ObjectDiff<'a> {
     const PATH: &'static str = "/object/diff";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -40,6 +44,8 @@ pub struct ObjectGet<'a> {
 
 impl<'a> ApiRequest for ObjectGet<'a> {
     const PATH: &'static str = "/object/get";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -50,6 +56,8 @@ pub struct ObjectLinks<'a> {
 
 impl<'a> ApiRequest for ObjectLinks<'a> {
     const PATH: &'static str = "/object/links";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Copy, Clone)]
@@ -107,6 +115,8 @@ pub struct ObjectStat<'a> {
 
 impl<'a> ApiRequest for ObjectStat<'a> {
     const PATH: &'static str = "/object/stat";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[cfg(test)]
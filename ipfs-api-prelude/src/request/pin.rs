@@ -7,14 +7,20 @@
 //
 
 use crate::request::ApiRequest;
-use serde::Serialize;
+use crate::serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 
-#[derive(Serialize)]
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
 pub struct PinAdd<'a> {
     #[serde(rename = "arg")]
     pub key: &'a str,
 
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
     pub recursive: Option<bool>,
+
+    /// Stream progress updates (the number of nodes pinned so far).
+    #[cfg_attr(feature = "with-builder", builder(default))]
     pub progress: bool,
 }
 
@@ -33,6 +39,8 @@ pub struct PinLs<'a> {
 
 impl<'a> ApiRequest for PinLs<'a> {
     const PATH: &'static str = "/pin/ls";
+
+    const IDEMPOTENT: bool = true;
 }
 
 #[derive(Serialize)]
@@ -46,3 +54,300 @@ pub struct PinRm<'a> {
 impl<'a> ApiRequest for PinRm<'a> {
     const PATH: &'static str = "/pin/rm";
 }
+
+#[derive(Serialize)]
+pub struct PinUpdate<'a> {
+    #[serde(rename = "arg")]
+    pub from: &'a str,
+
+    #[serde(rename = "arg")]
+    pub to: &'a str,
+
+    pub unpin: Option<bool>,
+}
+
+impl<'a> ApiRequest for PinUpdate<'a> {
+    const PATH: &'static str = "/pin/update";
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+pub struct PinVerify {
+    /// Also write the hashes of non-broken pins.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub verbose: Option<bool>,
+
+    /// Write just hashes of broken pins.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub quiet: Option<bool>,
+}
+
+impl ApiRequest for PinVerify {
+    const PATH: &'static str = "/pin/verify";
+}
+
+/// Status of a pin on a remote pinning service, as defined by the
+/// [Pinning Service API](https://ipfs.github.io/pinning-services-api-spec/).
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PinRemoteStatus {
+    Queued,
+    Pinning,
+    Pinned,
+    Failed,
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+pub struct PinRemoteAdd<'a> {
+    #[serde(rename = "arg")]
+    pub path: &'a str,
+
+    /// Name of the remote pinning service to use.
+    pub service: &'a str,
+
+    /// An optional name for the pin.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub name: Option<&'a str>,
+
+    /// Add to the queue on the remote service and return immediately (does not
+    /// wait for the pin to be pinned).
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub background: Option<bool>,
+}
+
+impl<'a> ApiRequest for PinRemoteAdd<'a> {
+    const PATH: &'static str = "/pin/remote/add";
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Default)]
+pub struct PinRemoteLs<'a> {
+    /// Name of the remote pinning service to use.
+    pub service: &'a str,
+
+    /// Return pins with names that contain the value provided (case-sensitive,
+    /// exact match).
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub name: Option<&'a str>,
+
+    /// Return pins for the specified CIDs.
+    #[cfg_attr(feature = "with-builder", builder(default))]
+    pub cid: &'a [&'a str],
+
+    /// Return pins with the specified statuses. Defaults to `pinned`.
+    #[cfg_attr(feature = "with-builder", builder(default))]
+    pub status: &'a [PinRemoteStatus],
+}
+
+impl<'a> Serialize for PinRemoteLs<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = 2 + self.cid.len() + self.status.len();
+        let mut state = serializer.serialize_struct("PinRemoteLs", len)?;
+
+        state.serialize_field("service", self.service)?;
+        state.serialize_field("name", &self.name)?;
+
+        for cid in self.cid {
+            state.serialize_field("cid", cid)?;
+        }
+
+        for status in self.status {
+            state.serialize_field("status", status)?;
+        }
+
+        state.end()
+    }
+}
+
+impl<'a> ApiRequest for PinRemoteLs<'a> {
+    const PATH: &'static str = "/pin/remote/ls";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Default)]
+pub struct PinRemoteRm<'a> {
+    /// Name of the remote pinning service to use.
+    pub service: &'a str,
+
+    /// Remove pins with names that contain the value provided (case-sensitive,
+    /// exact match).
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub name: Option<&'a str>,
+
+    /// Remove pins for the specified CIDs.
+    #[cfg_attr(feature = "with-builder", builder(default))]
+    pub cid: &'a [&'a str],
+
+    /// Remove pins with the specified statuses. Defaults to `pinned`.
+    #[cfg_attr(feature = "with-builder", builder(default))]
+    pub status: &'a [PinRemoteStatus],
+
+    /// Allow removal of multiple pins matching the query without additional
+    /// confirmation.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub force: Option<bool>,
+}
+
+impl<'a> Serialize for PinRemoteRm<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = 3 + self.cid.len() + self.status.len();
+        let mut state = serializer.serialize_struct("PinRemoteRm", len)?;
+
+        state.serialize_field("service", self.service)?;
+        state.serialize_field("name", &self.name)?;
+
+        for cid in self.cid {
+            state.serialize_field("cid", cid)?;
+        }
+
+        for status in self.status {
+            state.serialize_field("status", status)?;
+        }
+
+        state.serialize_field("force", &self.force)?;
+
+        state.end()
+    }
+}
+
+impl<'a> ApiRequest for PinRemoteRm<'a> {
+    const PATH: &'static str = "/pin/remote/rm";
+}
+
+#[derive(Serialize)]
+pub struct PinRemoteServiceAdd<'a> {
+    #[serde(rename = "arg")]
+    pub service: &'a str,
+
+    #[serde(rename = "arg")]
+    pub endpoint: &'a str,
+
+    #[serde(rename = "arg")]
+    pub key: &'a str,
+}
+
+impl<'a> ApiRequest for PinRemoteServiceAdd<'a> {
+    const PATH: &'static str = "/pin/remote/service/add";
+}
+
+#[derive(Serialize)]
+pub struct PinRemoteServiceLs {
+    /// Try to fetch and display current pin count on remote service (queued,
+    /// pinning, pinned and failed).
+    pub stat: bool,
+}
+
+impl ApiRequest for PinRemoteServiceLs {
+    const PATH: &'static str = "/pin/remote/service/ls";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[derive(Serialize)]
+pub struct PinRemoteServiceRm<'a> {
+    #[serde(rename = "arg")]
+    pub service: &'a str,
+}
+
+impl<'a> ApiRequest for PinRemoteServiceRm<'a> {
+    const PATH: &'static str = "/pin/remote/service/rm";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_pin_add_with_progress,
+        PinAdd {
+            key: "QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm",
+            recursive: Some(true),
+            progress: true,
+        },
+        "arg=QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm&recursive=true&progress=true"
+    );
+
+    serialize_url_test!(
+        test_serializes_pin_update,
+        PinUpdate {
+            from: "QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm",
+            to: "QmVLDAhCY3X9P2uRudKAryuQFPM5zqA3Yij1dY8FpGbL7T",
+            unpin: Some(false),
+        },
+        "arg=QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm&arg=QmVLDAhCY3X9P2uRudKAryuQFPM5zqA3Yij1dY8FpGbL7T&unpin=false"
+    );
+
+    serialize_url_test!(
+        test_serializes_pin_verify_with_options,
+        PinVerify {
+            verbose: Some(true),
+            quiet: Some(false),
+        },
+        "verbose=true&quiet=false"
+    );
+
+    serialize_url_test!(
+        test_serializes_pin_remote_add,
+        PinRemoteAdd {
+            path: "/ipfs/QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm",
+            service: "mysrv",
+            name: Some("backup"),
+            background: Some(true),
+        },
+        "arg=%2Fipfs%2FQmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm&service=mysrv&name=backup&background=true"
+    );
+
+    serialize_url_test!(
+        test_serializes_pin_remote_ls,
+        PinRemoteLs {
+            service: "mysrv",
+            ..Default::default()
+        },
+        "service=mysrv"
+    );
+
+    serialize_url_test!(
+        test_serializes_pin_remote_ls_with_filters,
+        PinRemoteLs {
+            service: "mysrv",
+            name: Some("backup"),
+            cid: &[
+                "QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm",
+                "QmVLDAhCY3X9P2uRudKAryuQFPM5zqA3Yij1dY8FpGbL7T"
+            ],
+            status: &[PinRemoteStatus::Queued, PinRemoteStatus::Failed],
+        },
+        "service=mysrv&name=backup&cid=QmQ5vhrL7uv6tuoN9KeVBwd4PwfQkXdVVmDLUZuTNxqgvm&cid=QmVLDAhCY3X9P2uRudKAryuQFPM5zqA3Yij1dY8FpGbL7T&status=queued&status=failed"
+    );
+
+    serialize_url_test!(
+        test_serializes_pin_remote_rm,
+        PinRemoteRm {
+            service: "mysrv",
+            status: &[PinRemoteStatus::Pinned],
+            force: Some(true),
+            ..Default::default()
+        },
+        "service=mysrv&status=pinned&force=true"
+    );
+
+    serialize_url_test!(
+        test_serializes_pin_remote_service_add,
+        PinRemoteServiceAdd {
+            service: "mysrv",
+            endpoint: "https://pinning.example.com/api/v1",
+            key: "secret",
+        },
+        "arg=mysrv&arg=https%3A%2F%2Fpinning.example.com%2Fapi%2Fv1&arg=secret"
+    );
+}
@@ -17,6 +17,8 @@ impl_skip_serialize!(PubsubLs);
 
 impl ApiRequest for PubsubLs {
     const PATH: &'static str = "/pubsub/ls";
+
+    const IDEMPOTENT: bool = true;
 }
 
 pub struct PubsubPeers<'a> {
@@ -44,6 +46,8 @@ impl<'a> Serialize for PubsubPeers<'a> {
 
 impl<'a> ApiRequest for PubsubPeers<'a> {
     const PATH: &'static str = "/pubsub/peers";
+
+    const IDEMPOTENT: bool = true;
 }
 
 pub struct PubsubPub<'a> {
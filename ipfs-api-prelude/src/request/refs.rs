@@ -14,4 +14,6 @@ impl_skip_serialize!(RefsLocal);
 
 impl ApiRequest for RefsLocal {
     const PATH: &'static str = "/refs/local";
+
+    const IDEMPOTENT: bool = true;
 }
@@ -0,0 +1,97 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use crate::request::ApiRequest;
+use serde::Serialize;
+
+pub struct RepoFsck;
+
+impl_skip_serialize!(RepoFsck);
+
+impl ApiRequest for RepoFsck {
+    const PATH: &'static str = "/repo/fsck";
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct RepoGc {
+    /// Stream errors as they are encountered, instead of stopping at the first one.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub stream_errors: Option<bool>,
+
+    /// Write minimal output.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub quiet: Option<bool>,
+}
+
+impl ApiRequest for RepoGc {
+    const PATH: &'static str = "/repo/gc";
+}
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct RepoStat {
+    /// Only report `RepoSize` and `StorageMax`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub size_only: Option<bool>,
+
+    /// Print sizes in human readable format (e.g., 1K 234M 2G).
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub human: Option<bool>,
+}
+
+impl ApiRequest for RepoStat {
+    const PATH: &'static str = "/repo/stat";
+
+    const IDEMPOTENT: bool = true;
+}
+
+pub struct RepoVerify;
+
+impl_skip_serialize!(RepoVerify);
+
+impl ApiRequest for RepoVerify {
+    const PATH: &'static str = "/repo/verify";
+}
+
+pub struct RepoVersion;
+
+impl_skip_serialize!(RepoVersion);
+
+impl ApiRequest for RepoVersion {
+    const PATH: &'static str = "/repo/version";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(test_serializes_repo_gc, RepoGc::default(), "");
+
+    serialize_url_test!(
+        test_serializes_repo_gc_with_options,
+        RepoGc {
+            stream_errors: Some(true),
+            quiet: Some(false),
+        },
+        "stream-errors=true&quiet=false"
+    );
+
+    serialize_url_test!(
+        test_serializes_repo_stat_with_options,
+        RepoStat {
+            size_only: Some(true),
+            human: Some(true),
+        },
+        "size-only=true&human=true"
+    );
+}
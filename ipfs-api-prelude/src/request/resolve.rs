@@ -0,0 +1,64 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use crate::request::ApiRequest;
+use serde::Serialize;
+use std::time::Duration;
+
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct Resolve<'a> {
+    /// The name to resolve, e.g. `/ipns/ipfs.io` or `/ipfs/<cid>/some/path`.
+    #[serde(rename = "arg")]
+    pub path: &'a str,
+
+    /// Resolve until the result is an IPFS name. Defaults to `true`.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub recursive: Option<bool>,
+
+    /// Number of records to request for DHT resolution.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub dht_record_count: Option<u32>,
+
+    /// Max time to collect values during DHT resolution.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    #[serde(serialize_with = "crate::global_opts::duration_as_secs_ns")]
+    pub dht_timeout: Option<Duration>,
+}
+
+impl<'a> ApiRequest for Resolve<'a> {
+    const PATH: &'static str = "/resolve";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_resolve,
+        Resolve {
+            path: "/ipns/ipfs.io",
+            ..Default::default()
+        },
+        "arg=%2Fipns%2Fipfs.io"
+    );
+
+    serialize_url_test!(
+        test_serializes_resolve_with_options,
+        Resolve {
+            path: "/ipns/ipfs.io",
+            recursive: Some(false),
+            dht_record_count: Some(16),
+            dht_timeout: Some(Duration::from_secs(30)),
+        },
+        "arg=%2Fipns%2Fipfs.io&recursive=false&dht-record-count=16&dht-timeout=30s0ns"
+    );
+}
@@ -0,0 +1,65 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use crate::request::ApiRequest;
+use serde::Serialize;
+use std::fmt::Display;
+
+#[derive(Serialize)]
+#[serde(bound = "A: Display")]
+pub struct RoutingFindPeer<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub peer: &'a A,
+}
+
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for RoutingFindPeer<'a, A> {
+    const PATH: &'static str = "/routing/findpeer";
+}
+
+#[derive(Serialize)]
+#[serde(bound = "A: Display")]
+pub struct RoutingFindProvs<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub key: &'a A,
+}
+
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for RoutingFindProvs<'a, A> {
+    const PATH: &'static str = "/routing/findprovs";
+}
+
+#[derive(Serialize)]
+pub struct RoutingGet<'a> {
+    #[serde(rename = "arg")]
+    pub key: &'a str,
+}
+
+impl<'a> ApiRequest for RoutingGet<'a> {
+    const PATH: &'static str = "/routing/get";
+}
+
+#[derive(Serialize)]
+#[serde(bound = "A: Display")]
+pub struct RoutingProvide<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub key: &'a A,
+}
+
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for RoutingProvide<'a, A> {
+    const PATH: &'static str = "/routing/provide";
+}
+
+/// The value is sent as a multipart body.
+#[derive(Serialize)]
+pub struct RoutingPut<'a> {
+    #[serde(rename = "arg")]
+    pub key: &'a str,
+}
+
+impl<'a> ApiRequest for RoutingPut<'a> {
+    const PATH: &'static str = "/routing/put";
+}
@@ -7,6 +7,8 @@
 //
 
 use crate::request::ApiRequest;
+use serde::Serialize;
+use std::time::Duration;
 
 pub struct StatsBitswap;
 
@@ -14,14 +16,54 @@ impl_skip_serialize!(StatsBitswap);
 
 impl ApiRequest for StatsBitswap {
     const PATH: &'static str = "/stats/bitswap";
+
+    const IDEMPOTENT: bool = true;
 }
 
-pub struct StatsBw;
+#[cfg_attr(feature = "with-builder", derive(TypedBuilder))]
+#[derive(Serialize, Default)]
+pub struct StatsBw<'a> {
+    /// Only show bandwidth for the given peer.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub peer: Option<&'a str>,
+
+    /// Only show bandwidth for the given protocol.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub proto: Option<&'a str>,
 
-impl_skip_serialize!(StatsBw);
+    /// Keep printing bandwidth stats until the request is dropped.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    pub poll: Option<bool>,
 
-impl ApiRequest for StatsBw {
+    /// Time between updates when polling. Defaults to 1 second.
+    #[cfg_attr(feature = "with-builder", builder(default, setter(strip_option)))]
+    #[serde(serialize_with = "crate::global_opts::duration_as_secs_ns")]
+    pub interval: Option<Duration>,
+}
+
+impl<'a> ApiRequest for StatsBw<'a> {
     const PATH: &'static str = "/stats/bw";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[derive(Serialize, Default)]
+pub struct StatsDht<'a> {
+    /// Only report on the named DHT, `wan` or `lan`.
+    #[serde(rename = "arg")]
+    pub dht: Option<&'a str>,
+}
+
+impl<'a> ApiRequest for StatsDht<'a> {
+    const PATH: &'static str = "/stats/dht";
+}
+
+pub struct StatsProvide;
+
+impl_skip_serialize!(StatsProvide);
+
+impl ApiRequest for StatsProvide {
+    const PATH: &'static str = "/stats/provide";
 }
 
 pub struct StatsRepo;
@@ -30,4 +72,30 @@ impl_skip_serialize!(StatsRepo);
 
 impl ApiRequest for StatsRepo {
     const PATH: &'static str = "/stats/repo";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(test_serializes_stats_bw, StatsBw::default(), "");
+
+    serialize_url_test!(
+        test_serializes_stats_dht,
+        StatsDht { dht: Some("wan") },
+        "arg=wan"
+    );
+
+    serialize_url_test!(
+        test_serializes_stats_bw_with_options,
+        StatsBw {
+            peer: None,
+            proto: Some("/ipfs/bitswap/1.2.0"),
+            poll: Some(true),
+            interval: Some(Duration::from_millis(500)),
+        },
+        "proto=%2Fipfs%2Fbitswap%2F1.2.0&poll=true&interval=0s500000000ns"
+    );
 }
@@ -7,6 +7,28 @@
 //
 
 use crate::request::ApiRequest;
+use serde::Serialize;
+use std::fmt::Display;
+
+pub struct SwarmAddrs;
+
+impl_skip_serialize!(SwarmAddrs);
+
+impl ApiRequest for SwarmAddrs {
+    const PATH: &'static str = "/swarm/addrs";
+
+    const IDEMPOTENT: bool = true;
+}
+
+pub struct SwarmAddrsListen;
+
+impl_skip_serialize!(SwarmAddrsListen);
+
+impl ApiRequest for SwarmAddrsListen {
+    const PATH: &'static str = "/swarm/addrs/listen";
+
+    const IDEMPOTENT: bool = true;
+}
 
 pub struct SwarmAddrsLocal;
 
@@ -14,6 +36,49 @@ impl_skip_serialize!(SwarmAddrsLocal);
 
 impl ApiRequest for SwarmAddrsLocal {
     const PATH: &'static str = "/swarm/addrs/local";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[derive(Serialize)]
+#[serde(bound = "A: Display")]
+pub struct SwarmDisconnect<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub address: &'a A,
+}
+
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for SwarmDisconnect<'a, A> {
+    const PATH: &'static str = "/swarm/disconnect";
+}
+
+pub struct SwarmFilters;
+
+impl_skip_serialize!(SwarmFilters);
+
+impl ApiRequest for SwarmFilters {
+    const PATH: &'static str = "/swarm/filters";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[derive(Serialize)]
+pub struct SwarmFiltersAdd<'a> {
+    #[serde(rename = "arg")]
+    pub filter: &'a str,
+}
+
+impl<'a> ApiRequest for SwarmFiltersAdd<'a> {
+    const PATH: &'static str = "/swarm/filters/add";
+}
+
+#[derive(Serialize)]
+pub struct SwarmFiltersRm<'a> {
+    #[serde(rename = "arg")]
+    pub filter: &'a str,
+}
+
+impl<'a> ApiRequest for SwarmFiltersRm<'a> {
+    const PATH: &'static str = "/swarm/filters/rm";
 }
 
 pub struct SwarmPeers;
@@ -22,4 +87,70 @@ impl_skip_serialize!(SwarmPeers);
 
 impl ApiRequest for SwarmPeers {
     const PATH: &'static str = "/swarm/peers";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[derive(Serialize)]
+#[serde(bound = "A: Display")]
+pub struct SwarmPeeringAdd<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub address: &'a A,
+}
+
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for SwarmPeeringAdd<'a, A> {
+    const PATH: &'static str = "/swarm/peering/add";
+}
+
+pub struct SwarmPeeringLs;
+
+impl_skip_serialize!(SwarmPeeringLs);
+
+impl ApiRequest for SwarmPeeringLs {
+    const PATH: &'static str = "/swarm/peering/ls";
+
+    const IDEMPOTENT: bool = true;
+}
+
+#[derive(Serialize)]
+#[serde(bound = "A: Display")]
+pub struct SwarmPeeringRm<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub peer: &'a A,
+}
+
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for SwarmPeeringRm<'a, A> {
+    const PATH: &'static str = "/swarm/peering/rm";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    serialize_url_test!(
+        test_serializes_swarm_filters_add,
+        SwarmFiltersAdd {
+            filter: "/ip4/192.168.0.0/ipcidr/16"
+        },
+        "arg=%2Fip4%2F192.168.0.0%2Fipcidr%2F16"
+    );
+
+    serialize_url_test!(
+        test_serializes_swarm_peering_rm,
+        SwarmPeeringRm {
+            peer: "12D3KooWQ2hL9NschcJ1Suqa1TybJc2ZaacqoQMBT3ziFC7Ye2BZ"
+        },
+        "arg=12D3KooWQ2hL9NschcJ1Suqa1TybJc2ZaacqoQMBT3ziFC7Ye2BZ"
+    );
+
+    #[cfg(feature = "with-typed-ids")]
+    serialize_url_test!(
+        test_serializes_swarm_peering_rm_typed,
+        SwarmPeeringRm {
+            peer: &"12D3KooWQ2hL9NschcJ1Suqa1TybJc2ZaacqoQMBT3ziFC7Ye2BZ"
+                .parse::<crate::ids::PeerId>()
+                .unwrap()
+        },
+        "arg=12D3KooWQ2hL9NschcJ1Suqa1TybJc2ZaacqoQMBT3ziFC7Ye2BZ"
+    );
 }
@@ -1,11 +1,13 @@
 use crate::request::ApiRequest;
 use serde::Serialize;
+use std::fmt::Display;
 
 #[derive(Serialize)]
-pub struct SwarmConnect<'a> {
-    #[serde(rename = "arg")]
-    pub peer: &'a str,
+#[serde(bound = "A: Display")]
+pub struct SwarmConnect<'a, A: ?Sized = str> {
+    #[serde(rename = "arg", serialize_with = "crate::ids::serialize_display")]
+    pub peer: &'a A,
 }
-impl<'a> ApiRequest for SwarmConnect<'a> {
+impl<'a, A: ?Sized + Display + Sync> ApiRequest for SwarmConnect<'a, A> {
     const PATH: &'static str = "/swarm/connect";
 }
@@ -25,4 +25,6 @@ pub struct TarCat<'a> {
 
 impl<'a> ApiRequest for TarCat<'a> {
     const PATH: &'static str = "/tar/cat";
+
+    const IDEMPOTENT: bool = true;
 }
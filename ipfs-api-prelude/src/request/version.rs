@@ -14,4 +14,6 @@ impl_skip_serialize!(Version);
 
 impl ApiRequest for Version {
     const PATH: &'static str = "/version";
+
+    const IDEMPOTENT: bool = true;
 }
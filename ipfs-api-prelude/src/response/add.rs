@@ -6,12 +6,45 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::ContentId;
+use crate::response::serde;
 use crate::serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AddResponse {
     pub name: String,
-    pub hash: String,
-    pub size: String,
+
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub hash: ContentId,
+
+    #[serde(deserialize_with = "serde::deserialize_u64")]
+    pub size: u64,
+}
+
+/// A message from the `/add` stream.
+///
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AddEvent {
+    /// Number of bytes of a file processed so far. Only sent when the
+    /// `progress` option is set.
+    #[serde(rename_all = "PascalCase")]
+    Progress {
+        name: String,
+
+        #[serde(deserialize_with = "serde::deserialize_u64")]
+        bytes: u64,
+    },
+
+    /// A file or directory has been added.
+    Added(AddResponse),
+}
+
+#[cfg(test)]
+mod tests {
+    deserialize_test!(v0_add_0, AddResponse);
+    deserialize_test!(v0_add_1, AddResponse);
+    deserialize_test!(v0_add_progress_0, AddEvent);
+    deserialize_test!(v0_add_progress_1, AddEvent);
 }
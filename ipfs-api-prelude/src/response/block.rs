@@ -6,26 +6,34 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::ContentId;
+use crate::response::serde;
 use crate::serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BlockPutResponse {
-    pub key: String,
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub key: ContentId,
+
     pub size: u64,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BlockRmResponse {
-    pub hash: String,
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub hash: ContentId,
+
     pub error: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BlockStatResponse {
-    pub key: String,
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub key: ContentId,
+
     pub size: u64,
 }
 
@@ -6,28 +6,29 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::Multiaddr;
 use crate::response::serde;
 use crate::serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BootstrapAddDefaultResponse {
-    #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub peers: Vec<String>,
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub peers: Vec<Multiaddr>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BootstrapListResponse {
-    #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub peers: Vec<String>,
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub peers: Vec<Multiaddr>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BootstrapRmAllResponse {
-    #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub peers: Vec<String>,
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub peers: Vec<Multiaddr>,
 }
 
 #[cfg(test)]
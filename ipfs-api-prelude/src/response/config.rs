@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 //
 
-use crate::serde::Deserialize;
+use crate::{config::KuboConfig, serde::Deserialize};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -18,6 +18,21 @@ pub struct ConfigResponse {
 
 pub type ConfigEditResponse = ();
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConfigProfileApplyResponse {
+    /// The config before the profile was applied.
+    pub old_cfg: KuboConfig,
+
+    /// The config with the profile applied.
+    pub new_cfg: KuboConfig,
+}
+
 pub type ConfigReplaceResponse = ();
 
 pub type ConfigShowResponse = String;
+
+#[cfg(test)]
+mod tests {
+    deserialize_test!(v0_config_profile_apply_0, ConfigProfileApplyResponse);
+}
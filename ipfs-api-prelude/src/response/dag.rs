@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::ContentId;
 use crate::response::serde;
 use crate::serde::Deserialize;
 use std::collections::HashMap;
@@ -36,11 +37,98 @@ pub struct DagPutResponse {
 
 #[derive(Debug, Deserialize)]
 pub struct Cid {
-    #[serde(rename = "/")]
-    pub cid_string: String,
+    #[serde(rename = "/", deserialize_with = "serde::deserialize_from_str")]
+    pub cid_string: ContentId,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DagImportRoot {
+    pub cid: Cid,
+
+    /// Empty if the root was pinned successfully, or if pinning was disabled.
+    #[serde(default)]
+    pub pin_error_msg: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DagImportStats {
+    pub block_count: u64,
+    pub block_bytes_count: u64,
+}
+
+/// Each line of a `dag/import` response carries either a root or, when
+/// requested, the final block stats.
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DagImportResponse {
+    pub root: Option<DagImportRoot>,
+    pub stats: Option<DagImportStats>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DagResolveResponse {
+    pub cid: Cid,
+
+    /// The part of the path that could not be resolved within the block.
+    pub rem_path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DagStat {
+    pub cid: Option<Cid>,
+
+    #[serde(default)]
+    pub size: u64,
+
+    #[serde(default)]
+    pub num_blocks: u64,
+}
+
+/// A progress update, or the final result, of `dag/stat`.
+///
+/// Daemons before Kubo 0.20 report `Size` and `NumBlocks` directly. Newer
+/// daemons report a summary with one entry per requested DAG in `DagStats`.
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DagStatResponse {
+    #[serde(default)]
+    pub size: u64,
+
+    #[serde(default)]
+    pub num_blocks: u64,
+
+    #[serde(default)]
+    pub unique_blocks: u64,
+
+    #[serde(default)]
+    pub total_size: u64,
+
+    #[serde(default)]
+    pub shared_size: u64,
+
+    #[serde(default)]
+    pub ratio: f32,
+
+    #[serde(
+        rename = "DagStats",
+        deserialize_with = "serde::deserialize_vec",
+        default
+    )]
+    pub dag_stats: Vec<DagStat>,
 }
 
 #[cfg(test)]
 mod tests {
     deserialize_test!(v0_dag_get_0, DagGetResponse);
+    deserialize_test!(v0_dag_import_0, DagImportResponse);
+    deserialize_test!(v0_dag_import_1, DagImportResponse);
+    deserialize_test!(v0_dag_resolve_0, DagResolveResponse);
+    deserialize_test!(v0_dag_stat_0, DagStatResponse);
+    deserialize_test!(v0_dag_stat_1, DagStatResponse);
 }
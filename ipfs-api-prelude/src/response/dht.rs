@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::{Multiaddr, PeerId};
 use crate::response::serde;
 use crate::serde::{
     de::{Deserializer, Error},
@@ -50,18 +51,19 @@ impl<'de> Deserialize<'de> for DhtType {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DhtPeerResponse {
-    #[serde(rename = "ID")]
-    pub id: String,
+    #[serde(rename = "ID", deserialize_with = "serde::deserialize_from_str")]
+    pub id: PeerId,
 
-    #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub addrs: Vec<String>,
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub addrs: Vec<Multiaddr>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DhtMessage {
-    #[serde(rename = "ID")]
-    pub id: String,
+    /// The peer the event is about, if any.
+    #[serde(rename = "ID", deserialize_with = "serde::deserialize_option_peer_id")]
+    pub id: Option<PeerId>,
 
     #[serde(rename = "Type")]
     pub typ: DhtType,
@@ -83,3 +85,17 @@ pub type DhtProvideResponse = DhtMessage;
 pub type DhtPutResponse = DhtMessage;
 
 pub type DhtQueryResponse = DhtMessage;
+
+#[cfg(test)]
+mod tests {
+    deserialize_test!(v0_dht_get_0, DhtGetResponse);
+    deserialize_test!(v0_dht_query_0, DhtQueryResponse);
+
+    #[test]
+    fn test_value_without_peer() {
+        let raw = include_str!("tests/v0_dht_get_0.json");
+        let res: super::DhtGetResponse = serde_json::from_str(raw).unwrap();
+
+        assert!(res.id.is_none());
+    }
+}
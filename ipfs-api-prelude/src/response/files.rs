@@ -55,6 +55,13 @@ pub struct FilesStatResponse {
     pub size_local: Option<u64>,
     #[serde(default)]
     pub local: Option<bool>,
+
+    #[serde(default)]
+    pub mode: Option<u32>,
+    #[serde(default)]
+    pub mtime: Option<i64>,
+    #[serde(default)]
+    pub mtime_nsecs: Option<u32>,
 }
 
 pub type FilesWriteResponse = ();
@@ -65,4 +72,5 @@ pub type FilesChcidResponse = ();
 mod tests {
     deserialize_test!(v0_files_ls_0, FilesLsResponse);
     deserialize_test!(v0_files_stat_0, FilesStatResponse);
+    deserialize_test!(v0_files_stat_1, FilesStatResponse);
 }
@@ -6,19 +6,20 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::{Multiaddr, PeerId};
 use crate::response::serde;
 use crate::serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct IdResponse {
-    #[serde(rename = "ID")]
-    pub id: String,
+    #[serde(rename = "ID", deserialize_with = "serde::deserialize_from_str")]
+    pub id: PeerId,
 
     pub public_key: String,
 
-    #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub addresses: Vec<String>,
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub addresses: Vec<Multiaddr>,
     #[serde(deserialize_with = "serde::deserialize_vec")]
     pub protocols: Vec<String>,
 
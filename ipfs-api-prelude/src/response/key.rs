@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::PeerId;
 use crate::response::serde;
 use crate::serde::Deserialize;
 
@@ -13,7 +14,9 @@ use crate::serde::Deserialize;
 #[serde(rename_all = "PascalCase")]
 pub struct KeyPair {
     pub name: String,
-    pub id: String,
+
+    #[serde(deserialize_with = "serde::deserialize_peer_id")]
+    pub id: PeerId,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,6 +28,8 @@ pub struct KeyPairList {
 
 pub type KeyGenResponse = KeyPair;
 
+pub type KeyImportResponse = KeyPair;
+
 pub type KeyListResponse = KeyPairList;
 
 #[derive(Debug, Deserialize)]
@@ -38,9 +43,25 @@ pub struct KeyRenameResponse {
 
 pub type KeyRmResponse = KeyPairList;
 
+pub type KeyRotateResponse = KeyPair;
+
 #[cfg(test)]
 mod tests {
     deserialize_test!(v0_key_gen_0, KeyGenResponse);
+    deserialize_test!(v0_key_import_0, KeyImportResponse);
     deserialize_test!(v0_key_list_0, KeyListResponse);
     deserialize_test!(v0_key_rename_0, KeyRenameResponse);
+    deserialize_test!(v0_key_rotate_0, KeyRotateResponse);
+
+    #[cfg(feature = "with-typed-ids")]
+    #[test]
+    fn test_base36_key_id() {
+        let raw = include_str!("tests/v0_key_import_0.json");
+        let res: super::KeyImportResponse = serde_json::from_str(raw).unwrap();
+
+        assert_eq!(
+            res.id.to_string(),
+            "12D3KooWRBy97UB99e3J6hiPesre1MZeuNQvfan4gBziswrRJsNK"
+        );
+    }
 }
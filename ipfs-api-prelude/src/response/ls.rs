@@ -27,6 +27,11 @@ pub struct IpfsFileHeader {
 
     #[serde(rename = "Type")]
     pub typ: u32,
+
+    #[serde(default)]
+    pub mode: Option<u32>,
+    #[serde(default)]
+    pub mod_time: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -40,4 +45,5 @@ pub struct LsResponse {
 mod tests {
     deserialize_test!(v0_ls_0, LsResponse);
     deserialize_test!(v0_ls_1, LsResponse);
+    deserialize_test!(v0_ls_2, LsResponse);
 }
@@ -37,6 +37,7 @@ pub use self::pubsub::*;
 pub use self::refs::*;
 pub use self::repo::*;
 pub use self::resolve::*;
+pub use self::routing::*;
 pub use self::shutdown::*;
 pub use self::stats::*;
 pub use self::swarm::*;
@@ -88,6 +89,7 @@ mod pubsub;
 mod refs;
 mod repo;
 mod resolve;
+mod routing;
 mod serde;
 mod shutdown;
 mod stats;
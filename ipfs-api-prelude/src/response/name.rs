@@ -6,7 +6,59 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::response::serde;
 use crate::serde::Deserialize;
+use std::time::Duration;
+
+/// Decoded contents of an IPNS record.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NameInspectEntry {
+    pub value: String,
+
+    pub validity_type: Option<u64>,
+
+    /// RFC 3339 timestamp after which the record expires.
+    pub validity: Option<String>,
+
+    pub sequence: Option<u64>,
+
+    #[serde(
+        rename = "TTL",
+        deserialize_with = "serde::deserialize_option_duration",
+        default
+    )]
+    pub ttl: Option<Duration>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NameInspectValidation {
+    pub valid: bool,
+
+    #[serde(default)]
+    pub reason: String,
+
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NameInspectResponse {
+    pub entry: NameInspectEntry,
+
+    pub pb_size: u64,
+
+    #[serde(default)]
+    pub signature_type: String,
+
+    #[serde(default)]
+    pub hex_dump: String,
+
+    /// Only set when a key to verify against was given.
+    pub validation: Option<NameInspectValidation>,
+}
 
 #[derive(Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -15,6 +67,25 @@ pub struct NamePublishResponse {
     pub value: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NamePubsubCancelResponse {
+    pub canceled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NamePubsubStateResponse {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NamePubsubSubsResponse {
+    #[serde(deserialize_with = "serde::deserialize_vec")]
+    pub strings: Vec<String>,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct NameResolveResponse {
@@ -23,5 +94,10 @@ pub struct NameResolveResponse {
 
 #[cfg(test)]
 mod tests {
+    deserialize_test!(v0_name_inspect_0, NameInspectResponse);
+    deserialize_test!(v0_name_inspect_1, NameInspectResponse);
+    deserialize_test!(v0_name_pubsub_cancel_0, NamePubsubCancelResponse);
+    deserialize_test!(v0_name_pubsub_state_0, NamePubsubStateResponse);
+    deserialize_test!(v0_name_pubsub_subs_0, NamePubsubSubsResponse);
     deserialize_test!(v0_name_resolve_0, NameResolveResponse);
 }
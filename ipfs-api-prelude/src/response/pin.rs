@@ -6,6 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::ContentId;
+use crate::request::PinRemoteStatus;
 use crate::response::serde;
 use crate::serde::Deserialize;
 use std::collections::HashMap;
@@ -13,8 +15,9 @@ use std::collections::HashMap;
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PinAddResponse {
-    #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub pins: Vec<String>,
+    /// Only set on the final message when streaming progress.
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str", default)]
+    pub pins: Vec<ContentId>,
 
     pub progress: Option<i32>,
 }
@@ -36,12 +39,106 @@ pub struct PinLsResponse {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct PinRmResponse {
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub pins: Vec<ContentId>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PinUpdateResponse {
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub pins: Vec<ContentId>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PinVerifyBadNode {
+    pub cid: String,
+    pub err: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PinVerifyResponse {
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub cid: ContentId,
+
+    pub err: Option<String>,
+
+    #[serde(default)]
+    pub ok: bool,
+
+    #[serde(deserialize_with = "serde::deserialize_vec", default)]
+    pub bad_nodes: Vec<PinVerifyBadNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemotePin {
+    pub status: PinRemoteStatus,
+
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub cid: ContentId,
+
+    #[serde(default)]
+    pub name: String,
+}
+
+pub type PinRemoteAddResponse = RemotePin;
+
+pub type PinRemoteLsResponse = RemotePin;
+
+pub type PinRemoteRmResponse = ();
+
+pub type PinRemoteServiceAddResponse = ();
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PinRemotePinCount {
+    pub queued: u64,
+    pub pinning: u64,
+    pub pinned: u64,
+    pub failed: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PinRemoteServiceStat {
+    /// Either `valid` or `invalid`.
+    pub status: String,
+
+    pub pin_count: Option<PinRemotePinCount>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PinRemoteService {
+    pub service: String,
+    pub api_endpoint: String,
+
+    /// Only present if stats were requested.
+    pub stat: Option<PinRemoteServiceStat>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PinRemoteServiceLsResponse {
     #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub pins: Vec<String>,
+    pub remote_services: Vec<PinRemoteService>,
 }
 
+pub type PinRemoteServiceRmResponse = ();
+
 #[cfg(test)]
 mod tests {
     deserialize_test!(v0_pin_ls_0, PinLsResponse);
     deserialize_test!(v0_pin_add_0, PinAddResponse);
+    deserialize_test!(v0_pin_add_1, PinAddResponse);
+    deserialize_test!(v0_pin_remote_add_0, PinRemoteAddResponse);
+    deserialize_test!(v0_pin_remote_ls_0, PinRemoteLsResponse);
+    deserialize_test!(v0_pin_remote_service_ls_0, PinRemoteServiceLsResponse);
+    deserialize_test!(v0_pin_remote_service_ls_1, PinRemoteServiceLsResponse);
+    deserialize_test!(v0_pin_update_0, PinUpdateResponse);
+    deserialize_test!(v0_pin_verify_0, PinVerifyResponse);
+    deserialize_test!(v0_pin_verify_1, PinVerifyResponse);
 }
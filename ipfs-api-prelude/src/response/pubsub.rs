@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::PeerId;
 use crate::response::serde;
 use crate::serde::Deserialize;
 
@@ -25,23 +26,41 @@ pub struct PubsubPeersResponse {
 
 pub type PubsubPubResponse = ();
 
+/// A message received from a pubsub subscription.
+///
 #[derive(Debug, Deserialize)]
-pub struct PubsubSubResponse {
-    pub from: String,
+pub struct PubsubMessage {
+    /// The peer that published the message.
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub from: PeerId,
 
-    #[serde(deserialize_with = "serde::deserialize_data_field")]
+    #[serde(default, deserialize_with = "serde::deserialize_data_field")]
     pub data: Vec<u8>,
 
-    #[serde(deserialize_with = "serde::deserialize_seqno_field")]
+    /// Sequence number set by the publisher. Together with `from`, it
+    /// identifies a message.
+    #[serde(default, deserialize_with = "serde::deserialize_seqno_field")]
     pub seqno: u64,
 
     #[serde(
+        default,
         rename = "topicIDs",
         deserialize_with = "serde::deserialize_topic_field"
     )]
     pub topic_ids: Vec<String>,
+
+    /// Signature of the message by the publisher, if the daemon returns it.
+    #[serde(default, deserialize_with = "serde::deserialize_option_data_field")]
+    pub signature: Option<Vec<u8>>,
+
+    /// Public key of the publisher, if the daemon returns it and it can't be
+    /// derived from `from`.
+    #[serde(default, deserialize_with = "serde::deserialize_option_data_field")]
+    pub key: Option<Vec<u8>>,
 }
 
+pub type PubsubSubResponse = PubsubMessage;
+
 #[cfg(test)]
 mod tests {
     deserialize_test!(v0_pubsub_ls_0, PubsubLsResponse);
@@ -49,4 +68,5 @@ mod tests {
     deserialize_test!(v0_pubsub_peers_0, PubsubPeersResponse);
     deserialize_test!(v0_pubsub_sub_0, PubsubSubResponse);
     deserialize_test!(v0_pubsub_sub_1, PubsubSubResponse);
+    deserialize_test!(v0_pubsub_sub_2, PubsubMessage);
 }
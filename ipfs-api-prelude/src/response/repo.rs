@@ -10,16 +10,17 @@ use crate::response::serde;
 use crate::serde::Deserialize;
 use std::collections::HashMap;
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct RepoFsckResponse {
     pub message: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct RepoGcResponse {
-    #[serde(deserialize_with = "serde::deserialize_hashmap")]
+    /// Missing when the entry only reports an error (see `stream-errors`).
+    #[serde(deserialize_with = "serde::deserialize_hashmap", default)]
     pub key: HashMap<String, String>,
     pub error: Option<String>,
 }
@@ -27,22 +28,30 @@ pub struct RepoGcResponse {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct RepoStatResponse {
+    // `NumObjects`, `RepoPath` and `Version` are omitted with `size-only`.
+    #[serde(deserialize_with = "serde::deserialize_u64", default)]
     pub num_objects: u64,
+    #[serde(deserialize_with = "serde::deserialize_u64")]
     pub repo_size: u64,
+    #[serde(default)]
     pub repo_path: String,
+    #[serde(default)]
     pub version: String,
+    #[serde(deserialize_with = "serde::deserialize_u64", default)]
+    pub storage_max: u64,
 }
 
 // Defined in go-ipfs:master core/commands/repo.go
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct RepoVerifyResponse {
+    #[serde(alias = "Msg")]
     pub message: String,
     // Could technically be an i64 but this is probably safest?
     pub progress: i32,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct RepoVersionResponse {
     pub version: String,
@@ -51,8 +60,11 @@ pub struct RepoVersionResponse {
 #[cfg(test)]
 mod tests {
     deserialize_test!(v0_repo_gc_0, RepoGcResponse);
+    deserialize_test!(v0_repo_gc_1, RepoGcResponse);
     deserialize_test!(v0_repo_stat_0, RepoStatResponse);
+    deserialize_test!(v0_repo_stat_1, RepoStatResponse);
     deserialize_test!(v0_repo_verify_0, RepoVerifyResponse);
     deserialize_test!(v0_repo_verify_1, RepoVerifyResponse);
+    deserialize_test!(v0_repo_verify_2, RepoVerifyResponse);
     deserialize_test!(v0_repo_version_0, RepoVersionResponse);
 }
@@ -8,7 +8,7 @@
 
 use crate::serde::Deserialize;
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ResolveResponse {
     pub path: String,
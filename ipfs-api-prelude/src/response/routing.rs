@@ -0,0 +1,25 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use crate::response::DhtMessage;
+
+pub type RoutingFindPeerResponse = DhtMessage;
+
+pub type RoutingFindProvsResponse = DhtMessage;
+
+pub type RoutingGetResponse = DhtMessage;
+
+pub type RoutingProvideResponse = DhtMessage;
+
+pub type RoutingPutResponse = DhtMessage;
+
+#[cfg(test)]
+mod tests {
+    deserialize_test!(v0_routing_findprovs_0, RoutingFindProvsResponse);
+    deserialize_test!(v0_routing_get_0, RoutingGetResponse);
+}
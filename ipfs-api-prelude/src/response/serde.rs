@@ -6,12 +6,15 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::{parse_peer_id, Multiaddr, PeerId};
 use crate::serde::de::{Deserialize, Deserializer, Error, MapAccess, SeqAccess, Visitor};
 use multibase::decode;
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::fmt;
 use std::marker::PhantomData;
+use std::str::FromStr;
+use std::time::Duration;
 
 pub struct IntegerVisitor;
 
@@ -118,6 +121,57 @@ where
     deserializer.deserialize_option(VecVisitor(PhantomData))
 }
 
+/// Deserializes a string into any type that can be parsed from one.
+///
+pub fn deserialize_from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(Error::custom)
+}
+
+/// Deserializes a sequence of strings or null values as a vec of any type
+/// that can be parsed from a string.
+///
+pub fn deserialize_vec_from_str<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    deserialize_vec::<String, D>(deserializer)?
+        .into_iter()
+        .map(|s| s.parse().map_err(Error::custom))
+        .collect()
+}
+
+/// Deserializes a peer ID, which may be sent in its base58 form or as a
+/// `libp2p-key` CID.
+///
+pub fn deserialize_peer_id<'de, D>(deserializer: D) -> Result<PeerId, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_peer_id(&String::deserialize(deserializer)?).map_err(Error::custom)
+}
+
+/// Like `deserialize_peer_id`, but null values and empty strings
+/// deserialize to `None`.
+///
+pub fn deserialize_option_peer_id<'de, D>(deserializer: D) -> Result<Option<PeerId>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.is_empty() => parse_peer_id(&s).map(Some).map_err(Error::custom),
+        _ => Ok(None),
+    }
+}
+
 /// Deserializes a map or null values as a HashMap.
 ///
 pub fn deserialize_hashmap<'de, T, D>(deserializer: D) -> Result<HashMap<String, T>, D::Error>
@@ -171,6 +225,198 @@ where
     deserializer.deserialize_option(MapVisitor(PhantomData))
 }
 
+/// Deserializes a map of peer IDs to their addresses, or null values as an
+/// empty HashMap.
+///
+pub fn deserialize_peer_addrs<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<PeerId, Vec<Multiaddr>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_hashmap::<Vec<String>, D>(deserializer)?
+        .into_iter()
+        .map(|(peer, addrs)| {
+            let peer = parse_peer_id(&peer).map_err(Error::custom)?;
+            let addrs = addrs
+                .into_iter()
+                .map(|addr| addr.parse().map_err(Error::custom))
+                .collect::<Result<_, _>>()?;
+
+            Ok((peer, addrs))
+        })
+        .collect()
+}
+
+/// Deserializes a `u64` that may be sent as either a number or a string.
+///
+pub fn deserialize_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct U64Visitor;
+
+    impl<'de> Visitor<'de> for U64Visitor {
+        type Value = u64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("unsigned integer or numeric string")
+        }
+
+        fn visit_u64<E>(self, num: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(num)
+        }
+
+        fn visit_i64<E>(self, num: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            num.try_into().map_err(Error::custom)
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            s.trim().parse().map_err(Error::custom)
+        }
+    }
+
+    deserializer.deserialize_any(U64Visitor)
+}
+
+/// Deserializes a `Duration` that may be sent as either an integer number of
+/// nanoseconds, or a Go duration string such as `1h2m3.5s`. Null values and
+/// strings that aren't durations, such as `n/a`, deserialize to zero.
+///
+pub fn deserialize_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_option_duration(deserializer).map(Option::unwrap_or_default)
+}
+
+/// Like `deserialize_duration`, but null values and strings that aren't
+/// durations deserialize to `None`.
+///
+pub fn deserialize_option_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct DurationVisitor;
+
+    impl<'de> Visitor<'de> for DurationVisitor {
+        type Value = Option<Duration>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("nanoseconds or duration string")
+        }
+
+        fn visit_u64<E>(self, nanos: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Some(Duration::from_nanos(nanos)))
+        }
+
+        fn visit_i64<E>(self, nanos: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            let nanos: u64 = nanos.try_into().map_err(Error::custom)?;
+
+            Ok(Some(Duration::from_nanos(nanos)))
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            // Strings that aren't durations, such as the `n/a` sent when
+            // there is no measurement, are treated as missing.
+            //
+            Ok(parse_go_duration(s))
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+    }
+
+    deserializer.deserialize_any(DurationVisitor)
+}
+
+/// Parses the output of Go's `time.Duration.String()`. Negative durations are
+/// rejected.
+///
+fn parse_go_duration(s: &str) -> Option<Duration> {
+    if s == "0" {
+        return Some(Duration::ZERO);
+    }
+
+    let mut rest = s.strip_prefix('+').unwrap_or(s);
+    let mut total = Duration::ZERO;
+
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_len);
+
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+
+        let unit_nanos: u64 = match unit {
+            "ns" => 1,
+            "us" | "\u{b5}s" | "\u{3bc}s" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60 * 1_000_000_000,
+            "h" => 60 * 60 * 1_000_000_000,
+            _ => return None,
+        };
+
+        let (whole, frac) = number.split_once('.').unwrap_or((number, ""));
+
+        if whole.is_empty() && frac.is_empty() {
+            return None;
+        }
+
+        let whole: u64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().ok()?
+        };
+        let mut nanos = whole.checked_mul(unit_nanos)?;
+
+        // Scale each fractional digit down by the unit, so e.g. `1.5s` adds
+        // 500ms without going through floating point.
+        let mut scale = unit_nanos;
+
+        for digit in frac.chars() {
+            scale /= 10;
+            nanos = nanos.checked_add(u64::from(digit.to_digit(10)?) * scale)?;
+        }
+
+        total = total.checked_add(Duration::from_nanos(nanos))?;
+        rest = tail;
+    }
+
+    Some(total)
+}
+
 pub fn deserialize_data_field<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
 where
     D: Deserializer<'de>,
@@ -182,6 +428,25 @@ where
     Ok(data)
 }
 
+/// Deserializes an optional multibase-encoded field. Null values and empty
+/// strings deserialize to `None`.
+///
+pub fn deserialize_option_data_field<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let data: Option<&str> = Deserialize::deserialize(deserializer)?;
+
+    match data {
+        Some(data) if !data.is_empty() => {
+            let (_, data) = decode(data).map_err(Error::custom)?;
+
+            Ok(Some(data))
+        }
+        _ => Ok(None),
+    }
+}
+
 pub fn deserialize_seqno_field<'de, D>(deserializer: D) -> Result<u64, D::Error>
 where
     D: Deserializer<'de>,
@@ -189,15 +454,16 @@ where
     let seqno: &str = Deserialize::deserialize(deserializer)?;
 
     let (_, seqno) = decode(seqno).map_err(Error::custom)?;
-    let seqno = read_be_u64(&mut seqno.as_ref());
 
-    Ok(seqno)
+    read_be_u64(&seqno).ok_or_else(|| Error::custom("seqno is longer than 8 bytes"))
 }
 
-fn read_be_u64(input: &mut &[u8]) -> u64 {
-    let (int_bytes, rest) = input.split_at(std::mem::size_of::<u64>());
-    *input = rest;
-    u64::from_be_bytes(int_bytes.try_into().unwrap())
+fn read_be_u64(input: &[u8]) -> Option<u64> {
+    if input.len() > std::mem::size_of::<u64>() {
+        return None;
+    }
+
+    Some(input.iter().fold(0, |int, byte| int << 8 | *byte as u64))
 }
 
 pub fn deserialize_topic_field<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
@@ -216,3 +482,43 @@ where
 
     Ok(topics)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_go_duration, read_be_u64};
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_go_duration() {
+        assert_eq!(parse_go_duration("0"), Some(Duration::ZERO));
+        assert_eq!(parse_go_duration("0s"), Some(Duration::ZERO));
+        assert_eq!(parse_go_duration("250ns"), Some(Duration::from_nanos(250)));
+        assert_eq!(
+            parse_go_duration("12.345\u{b5}s"),
+            Some(Duration::from_nanos(12_345))
+        );
+        assert_eq!(
+            parse_go_duration("23.456789ms"),
+            Some(Duration::from_nanos(23_456_789))
+        );
+        assert_eq!(
+            parse_go_duration("1h2m3.5s"),
+            Some(Duration::from_millis(3_723_500))
+        );
+        assert_eq!(parse_go_duration(""), None);
+        assert_eq!(parse_go_duration("-1s"), None);
+        assert_eq!(parse_go_duration("1d"), None);
+        assert_eq!(parse_go_duration("ms"), None);
+    }
+
+    #[test]
+    fn test_read_be_u64() {
+        assert_eq!(read_be_u64(&[]), Some(0));
+        assert_eq!(read_be_u64(&[1, 0]), Some(256));
+        assert_eq!(
+            read_be_u64(&[0x16, 0xce, 0xc8, 0x9f, 0x51, 0xa2, 0x4c, 0x40]),
+            Some(0x16cec89f51a24c40)
+        );
+        assert_eq!(read_be_u64(&[0; 9]), None);
+    }
+}
@@ -6,23 +6,91 @@
 // copied, modified, or distributed except according to those terms.
 //
 
-use crate::response::{BitswapStatResponse, RepoStatResponse};
+use crate::ids::PeerId;
+use crate::response::{serde, BitswapStatResponse, RepoStatResponse};
 use crate::serde::Deserialize;
+use std::time::Duration;
 
 pub type StatsBitswapResponse = BitswapStatResponse;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct StatsBwResponse {
+    #[serde(deserialize_with = "serde::deserialize_u64")]
     pub total_in: u64,
+
+    #[serde(deserialize_with = "serde::deserialize_u64")]
     pub total_out: u64,
+
     pub rate_in: f64,
     pub rate_out: f64,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StatsDhtPeer {
+    #[serde(rename = "ID", deserialize_with = "serde::deserialize_from_str")]
+    pub id: PeerId,
+
+    pub connected: bool,
+
+    #[serde(default)]
+    pub agent_version: String,
+
+    /// RFC 3339 timestamp of the last time the peer was useful to a query.
+    #[serde(default)]
+    pub last_useful_at: String,
+
+    /// RFC 3339 timestamp of the last successful query to the peer.
+    #[serde(default)]
+    pub last_queried_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StatsDhtBucket {
+    #[serde(default)]
+    pub last_refresh: String,
+
+    #[serde(deserialize_with = "serde::deserialize_vec")]
+    pub peers: Vec<StatsDhtPeer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StatsDhtResponse {
+    /// Name of the DHT, `wan` or `lan`.
+    pub name: String,
+
+    #[serde(deserialize_with = "serde::deserialize_vec")]
+    pub buckets: Vec<StatsDhtBucket>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StatsProvideResponse {
+    pub total_provides: u64,
+
+    #[serde(deserialize_with = "serde::deserialize_duration")]
+    pub last_reprovide_duration: Duration,
+
+    pub last_reprovide_batch_size: u64,
+
+    /// Average time to provide a single CID. Only reported by newer daemons.
+    #[serde(deserialize_with = "serde::deserialize_option_duration", default)]
+    pub avg_provide_duration: Option<Duration>,
+
+    /// RFC 3339 timestamp of the last reprovide run. Only reported by newer
+    /// daemons.
+    pub last_run: Option<String>,
+}
+
 pub type StatsRepoResponse = RepoStatResponse;
 
 #[cfg(test)]
 mod tests {
     deserialize_test!(v0_stats_bw_0, StatsBwResponse);
+    deserialize_test!(v0_stats_dht_0, StatsDhtResponse);
+    deserialize_test!(v0_stats_provide_0, StatsProvideResponse);
+    deserialize_test!(v0_stats_provide_1, StatsProvideResponse);
 }
@@ -6,38 +6,65 @@
 // copied, modified, or distributed except according to those terms.
 //
 
+use crate::ids::{Multiaddr, PeerId};
 use crate::response::serde;
 use crate::serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmAddrsResponse {
+    /// Known addresses, keyed by peer ID.
+    #[serde(deserialize_with = "serde::deserialize_peer_addrs")]
+    pub addrs: HashMap<PeerId, Vec<Multiaddr>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmAddrsListenResponse {
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub strings: Vec<Multiaddr>,
+}
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SwarmAddrsLocalResponse {
-    #[serde(deserialize_with = "serde::deserialize_vec")]
-    pub strings: Vec<String>,
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub strings: Vec<Multiaddr>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SwarmAddrsConnectResponse {
     #[serde(deserialize_with = "serde::deserialize_vec")]
     pub strings: Vec<String>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SwarmAddrsDisconnectResponse {
     #[serde(deserialize_with = "serde::deserialize_vec")]
     pub strings: Vec<String>,
 }
 
-#[derive(Deserialize)]
+pub type SwarmDisconnectResponse = SwarmAddrsDisconnectResponse;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmFiltersResponse {
+    #[serde(deserialize_with = "serde::deserialize_vec")]
+    pub strings: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SwarmFiltersAddResponse {
     #[serde(deserialize_with = "serde::deserialize_vec")]
     pub strings: Vec<String>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SwarmFiltersRmResponse {
     #[serde(deserialize_with = "serde::deserialize_vec")]
@@ -53,9 +80,15 @@ pub struct SwarmPeerStream {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct SwarmPeer {
-    pub addr: String,
-    pub peer: String,
-    pub latency: String,
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub addr: Multiaddr,
+
+    #[serde(deserialize_with = "serde::deserialize_from_str")]
+    pub peer: PeerId,
+
+    /// Only reported when requested from the daemon.
+    #[serde(deserialize_with = "serde::deserialize_option_duration", default)]
+    pub latency: Option<Duration>,
     pub muxer: String,
 
     #[serde(deserialize_with = "serde::deserialize_vec")]
@@ -69,10 +102,60 @@ pub struct SwarmPeersResponse {
     pub peers: Vec<SwarmPeer>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmPeeringStatus {
+    #[serde(rename = "ID", deserialize_with = "serde::deserialize_from_str")]
+    pub id: PeerId,
+
+    pub status: String,
+}
+
+pub type SwarmPeeringAddResponse = SwarmPeeringStatus;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmPeeringPeer {
+    #[serde(rename = "ID", deserialize_with = "serde::deserialize_from_str")]
+    pub id: PeerId,
+
+    #[serde(deserialize_with = "serde::deserialize_vec_from_str")]
+    pub addrs: Vec<Multiaddr>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmPeeringLsResponse {
+    #[serde(deserialize_with = "serde::deserialize_vec")]
+    pub peers: Vec<SwarmPeeringPeer>,
+}
+
+pub type SwarmPeeringRmResponse = SwarmPeeringStatus;
+
 #[cfg(test)]
 mod tests {
+    deserialize_test!(v0_swarm_addrs_0, SwarmAddrsResponse);
+    deserialize_test!(v0_swarm_addrs_listen_0, SwarmAddrsListenResponse);
     deserialize_test!(v0_swarm_addrs_local_0, SwarmAddrsLocalResponse);
+    deserialize_test!(v0_swarm_disconnect_0, SwarmDisconnectResponse);
+    deserialize_test!(v0_swarm_filters_0, SwarmFiltersResponse);
+    deserialize_test!(v0_swarm_filters_add_0, SwarmFiltersAddResponse);
     deserialize_test!(v0_swarm_peers_0, SwarmPeersResponse);
     deserialize_test!(v0_swarm_peers_1, SwarmPeersResponse);
     deserialize_test!(v0_swarm_peers_2, SwarmPeersResponse);
+    deserialize_test!(v0_swarm_peers_3, SwarmPeersResponse);
+    deserialize_test!(v0_swarm_peering_add_0, SwarmPeeringAddResponse);
+    deserialize_test!(v0_swarm_peering_ls_0, SwarmPeeringLsResponse);
+
+    #[test]
+    fn test_unmeasured_latency_is_none() {
+        let raw = include_str!("tests/v0_swarm_peers_3.json");
+        let res: super::SwarmPeersResponse = serde_json::from_str(raw).unwrap();
+
+        assert_eq!(res.peers[0].latency, None);
+        assert_eq!(
+            res.peers[1].latency,
+            Some(std::time::Duration::from_nanos(83_412_519))
+        );
+    }
 }
@@ -22,8 +22,53 @@ pub struct VersionResponse {
     pub golang: Option<String>,
 }
 
+impl VersionResponse {
+    /// Returns the `(major, minor, patch)` version numbers, ignoring any
+    /// pre-release suffix such as `-rc1` or `-dev`.
+    pub fn semver(&self) -> Option<(u32, u32, u32)> {
+        let mut parts = self
+            .version
+            .split(['-', '+'])
+            .next()?
+            .splitn(3, '.')
+            .map(|part| part.parse::<u32>());
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) => Some((major, minor, patch)),
+            _ => None,
+        }
+    }
+
+    /// Whether the daemon serves the `/routing/*` endpoints, which replaced
+    /// `/dht/*` in Kubo 0.16.
+    pub fn has_routing_api(&self) -> bool {
+        matches!(self.semver(), Some(version) if version >= (0, 16, 0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     deserialize_test!(v0_version_0, VersionResponse);
     deserialize_test!(v0_version_1, VersionResponse);
+
+    fn version(version: &str) -> VersionResponse {
+        VersionResponse {
+            version: version.into(),
+            commit: String::new(),
+            repo: String::new(),
+            system: None,
+            golang: None,
+        }
+    }
+
+    #[test]
+    fn test_has_routing_api() {
+        assert!(!version("0.4.11").has_routing_api());
+        assert!(!version("0.15.0").has_routing_api());
+        assert!(version("0.16.0-rc1").has_routing_api());
+        assert!(version("0.22.0").has_routing_api());
+        assert!(!version("unknown").has_routing_api());
+    }
 }
@@ -0,0 +1,284 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use bytes::Bytes;
+use http::StatusCode;
+use std::{
+    collections::hash_map::RandomState,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+use tokio::time::sleep;
+use tracing::{event, Level};
+
+/// The kind of error a request failed with, as reported by a backend.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The connection could not be established, or was lost before the
+    /// response was read.
+    Connection,
+
+    /// The request timed out.
+    Timeout,
+
+    /// Any other error. These are never retried.
+    Other,
+}
+
+/// When and how often a backend sends a request again after it fails.
+///
+/// Only requests that are marked as `ApiRequest::IDEMPOTENT`, and that don't
+/// send a multipart body, are retried. Streaming responses are never retried.
+///
+/// ```no_run
+/// use ipfs_api::{IpfsClient, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let client = IpfsClient::default().with_retry_policy(RetryPolicy {
+///     max_attempts: 5,
+///     max_backoff: Duration::from_secs(30),
+///     ..Default::default()
+/// });
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The number of times a request is sent, including the first attempt.
+    pub max_attempts: u32,
+
+    /// The delay before the first retry, which doubles after each attempt.
+    pub initial_backoff: Duration,
+
+    /// The maximum delay between two attempts.
+    pub max_backoff: Duration,
+
+    /// Waits a random delay between half and all of the backoff, so clients
+    /// don't all retry at the same time.
+    pub jitter: bool,
+
+    /// Retry when the connection fails or is reset.
+    pub retry_on_connection: bool,
+
+    /// Retry when the request times out.
+    pub retry_on_timeout: bool,
+
+    /// Retry when the daemon responds with one of these status codes.
+    pub retry_on_status: Vec<StatusCode>,
+}
+
+impl Default for RetryPolicy {
+    /// Sends a request up to 3 times, backing off from 200 milliseconds, when
+    /// the connection fails, times out, or a proxy in front of the daemon
+    /// reports it as unavailable.
+    ///
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            retry_on_connection: true,
+            retry_on_timeout: true,
+            retry_on_status: vec![
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends each request only once. This is what backends use
+    /// unless another policy is set.
+    ///
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the delay to wait after the given number of failed attempts.
+    ///
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            // A randomly seeded hasher is enough of a random source here.
+            //
+            let random = RandomState::new().build_hasher().finish();
+            let half = backoff / 2;
+
+            half + half.mul_f64(random as f64 / u64::MAX as f64)
+        } else {
+            backoff
+        }
+    }
+
+    fn should_retry<E>(
+        &self,
+        res: &Result<(StatusCode, Bytes), E>,
+        classify: fn(&E) -> ErrorClass,
+    ) -> bool {
+        match res {
+            Ok((status, _)) => self.retry_on_status.contains(status),
+            Err(e) => match classify(e) {
+                ErrorClass::Connection => self.retry_on_connection,
+                ErrorClass::Timeout => self.retry_on_timeout,
+                ErrorClass::Other => false,
+            },
+        }
+    }
+
+    /// Calls `send` until it succeeds, fails with an error that shouldn't be
+    /// retried, or the attempts run out. Used by backends to implement
+    /// `request_raw`.
+    ///
+    pub async fn run<F, Fut, E>(
+        &self,
+        mut send: F,
+        classify: fn(&E) -> ErrorClass,
+    ) -> Result<(StatusCode, Bytes), E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<(StatusCode, Bytes), E>>,
+    {
+        let mut attempt = 1;
+
+        loop {
+            {
+                let res = send().await;
+
+                if attempt >= self.max_attempts || !self.should_retry(&res, classify) {
+                    return res;
+                }
+            }
+
+            let backoff = self.backoff(attempt);
+
+            event!(Level::DEBUG, attempt, ?backoff, "Retrying request");
+
+            sleep(backoff).await;
+
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            initial_backoff: Duration::ZERO,
+            jitter: false,
+            ..Default::default()
+        }
+    }
+
+    fn classify(e: &&str) -> ErrorClass {
+        match *e {
+            "reset" => ErrorClass::Connection,
+            _ => ErrorClass::Other,
+        }
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            jitter: false,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(100), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_backoff_with_jitter() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(2);
+
+            assert!(backoff >= Duration::from_millis(100));
+            assert!(backoff <= Duration::from_millis(200));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_failures() {
+        let mut responses = vec![
+            Ok((StatusCode::OK, Bytes::new())),
+            Ok((StatusCode::SERVICE_UNAVAILABLE, Bytes::new())),
+            Err("reset"),
+        ];
+        let res = policy().run(|| future(responses.pop()), classify).await;
+
+        assert_eq!(res, Ok((StatusCode::OK, Bytes::new())));
+        assert!(responses.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stops_after_max_attempts() {
+        let mut attempts = 0;
+        let res = policy()
+            .run(
+                || {
+                    attempts += 1;
+
+                    future(Some(Err("reset")))
+                },
+                classify,
+            )
+            .await;
+
+        assert_eq!(res, Err("reset"));
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_other_failures() {
+        let mut attempts = 0;
+        let res = policy()
+            .run(
+                || {
+                    attempts += 1;
+
+                    future(Some(Ok((StatusCode::INTERNAL_SERVER_ERROR, Bytes::new()))))
+                },
+                classify,
+            )
+            .await;
+
+        assert_eq!(res, Ok((StatusCode::INTERNAL_SERVER_ERROR, Bytes::new())));
+        assert_eq!(attempts, 1);
+    }
+
+    fn future(
+        res: Option<Result<(StatusCode, Bytes), &'static str>>,
+    ) -> futures::future::Ready<Result<(StatusCode, Bytes), &'static str>> {
+        futures::future::ready(res.expect("too many attempts"))
+    }
+}
@@ -0,0 +1,402 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+
+use crate::{ids::PeerId, request, response, BoxStream, IpfsApi};
+use futures::{future, ready, FutureExt, Stream, StreamExt};
+use std::{
+    collections::{HashSet, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{sleep, Sleep};
+use tracing::{event, Level};
+
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+const DEFAULT_DEDUP_CAPACITY: usize = 1024;
+
+/// How long a new subscription has to stay open, without a message, before
+/// it is considered restored.
+///
+const RESUBSCRIBE_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// An event from a `ResilientSubscription`.
+///
+#[derive(Debug)]
+pub enum SubscriptionEvent<E> {
+    /// A message published to the topic.
+    Message(response::PubsubMessage),
+
+    /// The subscription was lost, either with an error, or because the
+    /// daemon closed the stream.
+    Disconnected(Option<E>),
+
+    /// The topic has been re-subscribed. This is sent once the new
+    /// subscription delivers a message, or stays open for a few seconds.
+    Reconnected,
+}
+
+/// Remembers the ids of the most recent messages.
+///
+struct RecentMessages {
+    capacity: usize,
+    seen: HashSet<(PeerId, u64)>,
+    order: VecDeque<(PeerId, u64)>,
+}
+
+impl RecentMessages {
+    fn new(capacity: usize) -> RecentMessages {
+        RecentMessages {
+            capacity,
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Returns `true` if the message has not been seen before, and
+    /// remembers it.
+    ///
+    fn insert(&mut self, message: &response::PubsubMessage) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+
+        // `PeerId` is a `String`, unless typed ids are enabled, in which case
+        // it is `Copy`.
+        //
+        let id = (message.from.to_owned(), message.seqno);
+
+        if self.seen.contains(&id) {
+            return false;
+        }
+
+        self.order.push_back(id);
+        self.seen.extend(self.order.back().cloned());
+
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+
+        true
+    }
+}
+
+enum State<E> {
+    /// The grace period is set while a new subscription hasn't been confirmed
+    /// yet.
+    Subscribed(
+        BoxStream<response::PubsubMessage, E>,
+        Option<Pin<Box<Sleep>>>,
+    ),
+    Waiting(Pin<Box<Sleep>>),
+    Probing(BoxStream<response::VersionResponse, E>),
+}
+
+/// A pubsub subscription that re-subscribes when it is lost.
+///
+/// After a disconnect, the daemon is polled with exponential backoff until it
+/// responds, and the topic is subscribed to again. The backoff is only reset
+/// once the new subscription is working, so a subscription that is rejected
+/// by a running daemon is retried less and less often. Messages that were
+/// already received, identified by `(from, seqno)`, are skipped. The stream
+/// never ends on its own.
+///
+pub struct ResilientSubscription<'a, B>
+where
+    B: ?Sized + IpfsApi,
+{
+    client: &'a B,
+    topic: Vec<u8>,
+    state: State<B::Error>,
+    connected: bool,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    backoff: Duration,
+    recent: RecentMessages,
+    pending: Option<response::PubsubMessage>,
+}
+
+impl<'a, B> ResilientSubscription<'a, B>
+where
+    B: ?Sized + IpfsApi,
+{
+    pub(crate) fn new(client: &'a B, topic: &[u8]) -> ResilientSubscription<'a, B> {
+        ResilientSubscription {
+            client,
+            topic: topic.to_vec(),
+            state: State::Subscribed(client.pubsub_sub(topic), None),
+            connected: true,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            backoff: DEFAULT_INITIAL_BACKOFF,
+            recent: RecentMessages::new(DEFAULT_DEDUP_CAPACITY),
+            pending: None,
+        }
+    }
+
+    /// Sets the delay before the first reconnect attempt, which doubles
+    /// after each failed attempt up to `max`. Defaults to 500 milliseconds
+    /// and 30 seconds.
+    ///
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self.backoff = initial;
+        self
+    }
+
+    /// Sets the number of recent messages remembered to skip duplicates.
+    /// Defaults to 1024.
+    ///
+    pub fn with_dedup_capacity(mut self, capacity: usize) -> Self {
+        self.recent = RecentMessages::new(capacity);
+        self
+    }
+
+    fn wait(&mut self) {
+        event!(Level::DEBUG, backoff = ?self.backoff, "Waiting to reconnect pubsub subscription");
+
+        self.state = State::Waiting(Box::pin(sleep(self.backoff)));
+        self.backoff = (self.backoff * 2).min(self.max_backoff);
+    }
+
+    fn reconnected(&mut self) -> SubscriptionEvent<B::Error> {
+        event!(Level::DEBUG, "Pubsub subscription restored");
+
+        self.connected = true;
+        self.backoff = self.initial_backoff;
+
+        SubscriptionEvent::Reconnected
+    }
+
+    fn probe(&self) -> BoxStream<response::VersionResponse, B::Error> {
+        match self.client.build_base_request(request::Version, None) {
+            Ok(req) => self.client.request_stream_json(req),
+            Err(e) => Box::new(future::err(e).into_stream()),
+        }
+    }
+}
+
+impl<'a, B> Stream for ResilientSubscription<'a, B>
+where
+    B: ?Sized + IpfsApi,
+{
+    type Item = SubscriptionEvent<B::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(message) = this.pending.take() {
+            return Poll::Ready(Some(SubscriptionEvent::Message(message)));
+        }
+
+        loop {
+            match this.state {
+                State::Subscribed(ref mut stream, ref mut grace) => {
+                    let res = match stream.poll_next_unpin(cx) {
+                        Poll::Ready(res) => res,
+                        Poll::Pending => match grace {
+                            Some(delay) => {
+                                ready!(delay.poll_unpin(cx));
+
+                                *grace = None;
+
+                                return Poll::Ready(Some(this.reconnected()));
+                            }
+                            None => return Poll::Pending,
+                        },
+                    };
+                    let error = match res {
+                        Some(Ok(message)) => {
+                            if grace.take().is_some() {
+                                if this.recent.insert(&message) {
+                                    this.pending = Some(message);
+                                }
+
+                                return Poll::Ready(Some(this.reconnected()));
+                            }
+
+                            if this.recent.insert(&message) {
+                                return Poll::Ready(Some(SubscriptionEvent::Message(message)));
+                            }
+
+                            continue;
+                        }
+                        Some(Err(e)) => Some(e),
+                        None => None,
+                    };
+
+                    this.wait();
+
+                    if this.connected {
+                        this.connected = false;
+
+                        return Poll::Ready(Some(SubscriptionEvent::Disconnected(error)));
+                    }
+
+                    if let Some(e) = error {
+                        event!(Level::DEBUG, error = %e, "Pubsub subscription failed");
+                    }
+                }
+                State::Waiting(ref mut delay) => {
+                    ready!(delay.poll_unpin(cx));
+
+                    this.state = State::Probing(this.probe());
+                }
+                State::Probing(ref mut stream) => match ready!(stream.poll_next_unpin(cx)) {
+                    Some(Ok(_)) => {
+                        let grace = Box::pin(sleep(RESUBSCRIBE_GRACE_PERIOD));
+
+                        this.state =
+                            State::Subscribed(this.client.pubsub_sub(&this.topic), Some(grace));
+                    }
+                    Some(Err(e)) => {
+                        event!(Level::DEBUG, error = %e, "Pubsub daemon is unreachable");
+
+                        this.wait();
+                    }
+                    None => this.wait(),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_backend::{MockBackend, MockError, MockResponse};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::time::timeout;
+
+    const PEER: &str = "12D3KooWQh2LjxNEcM9tfGU53cCFcQxwNPFCrsWMn4nYMpV8fL2S";
+
+    /// A daemon that is always up, and answers the `n`th subscription with
+    /// `subscribe(n)`.
+    ///
+    fn daemon<F>(subscribe: F) -> MockBackend
+    where
+        F: 'static + Fn(usize) -> MockResponse + Send,
+    {
+        let count = AtomicUsize::new(0);
+
+        MockBackend::new(move |req| {
+            if req.starts_with("/version") {
+                MockBackend::body([r#"{"Version":"0.22.0","Commit":"","Repo":"14"}"#, "\n"])
+            } else {
+                subscribe(count.fetch_add(1, Ordering::SeqCst))
+            }
+        })
+    }
+
+    fn message_json(seqno: u64) -> String {
+        let seqno = multibase::encode(multibase::Base::Base64Url, seqno.to_be_bytes());
+
+        format!("{{\"from\":\"{}\",\"seqno\":\"{}\"}}\n", PEER, seqno)
+    }
+
+    fn subscriptions(client: &MockBackend) -> usize {
+        client
+            .requests()
+            .iter()
+            .filter(|req| req.starts_with("/pubsub/sub"))
+            .count()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_backs_off_while_subscription_is_rejected() {
+        let client = daemon(|_| Err(MockError::Disconnected));
+        let mut sub = ResilientSubscription::new(&client, b"topic")
+            .with_backoff(Duration::from_secs(1), Duration::from_secs(8));
+
+        assert!(matches!(
+            sub.next().await,
+            Some(SubscriptionEvent::Disconnected(Some(_)))
+        ));
+
+        // The daemon answers, but the subscription never works, so nothing
+        // else is reported.
+        //
+        assert!(timeout(Duration::from_secs(40), sub.next()).await.is_err());
+
+        // Subscribed at 0s, then after waiting 1s, 2s, 4s, and 8s four times.
+        //
+        assert_eq!(subscriptions(&client), 8);
+        assert_eq!(sub.backoff, Duration::from_secs(8));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnects_after_grace_period() {
+        let client = daemon(|n| match n {
+            0 => Err(MockError::Disconnected),
+            _ => MockBackend::pending(),
+        });
+        let mut sub = ResilientSubscription::new(&client, b"topic");
+
+        assert!(matches!(
+            sub.next().await,
+            Some(SubscriptionEvent::Disconnected(_))
+        ));
+        assert!(matches!(
+            sub.next().await,
+            Some(SubscriptionEvent::Reconnected)
+        ));
+        assert_eq!(subscriptions(&client), 2);
+        assert_eq!(sub.backoff, DEFAULT_INITIAL_BACKOFF);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnects_on_first_message() {
+        let client = daemon(|n| match n {
+            0 => MockBackend::body([message_json(1)]),
+            _ => MockBackend::body([message_json(1), message_json(2)]),
+        });
+        let mut sub = ResilientSubscription::new(&client, b"topic");
+
+        assert!(matches!(sub.next().await, Some(SubscriptionEvent::Message(m)) if m.seqno == 1));
+        assert!(matches!(
+            sub.next().await,
+            Some(SubscriptionEvent::Disconnected(None))
+        ));
+        assert!(matches!(
+            sub.next().await,
+            Some(SubscriptionEvent::Reconnected)
+        ));
+
+        // The first message was already received before the disconnect.
+        //
+        assert!(matches!(sub.next().await, Some(SubscriptionEvent::Message(m)) if m.seqno == 2));
+    }
+
+    fn message(from: &str, seqno: u64) -> response::PubsubMessage {
+        let seqno = multibase::encode(multibase::Base::Base64Url, seqno.to_be_bytes());
+        let json = format!(r#"{{"from":"{}","seqno":"{}"}}"#, from, seqno);
+
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_recent_messages() {
+        let peer = "12D3KooWQh2LjxNEcM9tfGU53cCFcQxwNPFCrsWMn4nYMpV8fL2S";
+        let other = "12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EqHWmweQAJU5SA";
+        let mut recent = RecentMessages::new(2);
+
+        assert!(recent.insert(&message(peer, 1)));
+        assert!(!recent.insert(&message(peer, 1)));
+        assert!(recent.insert(&message(other, 1)));
+        assert!(recent.insert(&message(peer, 2)));
+
+        // The first message was forgotten once the capacity was reached.
+        assert!(recent.insert(&message(peer, 1)));
+    }
+}
@@ -34,6 +34,10 @@
 //!
 //! With either the Hyper or Actix backend, you can specify the `with-builder` feature to enable a builder pattern to use when building requests.
 //!
+//! ### Typed Identifiers
+//!
+//! With either the Hyper or Actix backend, you can specify the `with-typed-ids` feature to parse CIDs, peer IDs and multiaddrs in responses into [`cid::Cid`](https://crates.io/crates/cid), [`PeerId`](https://crates.io/crates/libp2p-identity) and [`Multiaddr`](https://crates.io/crates/multiaddr), instead of leaving them as `String`s.
+//!
 //! ## Usage (DEPRECATED)
 //!
 //! ```toml
@@ -58,6 +62,14 @@
 //! ipfs-api = { version = "0.17.0", features = ["with-hyper-rustls"], default-features = false }
 //! ```
 //!
+//! The `with-typed-ids` feature is passed through to whichever backend is
+//! enabled:
+//!
+//! ```toml
+//! [dependencies]
+//! ipfs-api = { version = "0.17.0", features = ["with-typed-ids"] }
+//! ```
+//!
 //! To enable the builder pattern (default) use the `with-builder` feature:
 //!
 //! ```toml
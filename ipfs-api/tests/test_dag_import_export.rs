@@ -0,0 +1,67 @@
+// Copyright 2022 rust-ipfs-api Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[path = "test_support/lib.rs"]
+mod test_support;
+
+use test_support::client::{build_client, wait_for_server};
+use test_support::container::IpfsContainer;
+
+use futures::TryStreamExt;
+use ipfs_api::{request::DagImport, IpfsApi};
+use ipfs_api_versions::test_current_image;
+use std::io::Cursor;
+
+/// A CARv1 file holding a single raw block containing `Hello World!\n`.
+const HELLO_WORLD_CAR: &[u8] = include_bytes!("test_support/hello_world.car");
+
+const HELLO_WORLD_CID: &str = "bafkreiadxiqe4ugre3sgotaalycnqlueyijwm6ak6h2dxvkkg6aww2vtia";
+
+#[test_current_image]
+#[actix_rt::test]
+async fn test_dag_import_export(image_name: &str, image_tag: &str) {
+    let container = IpfsContainer::new("test_dag_import_export", image_name, image_tag)
+        .await
+        .unwrap();
+
+    let api_url = format!("http://{}:5001", container.ip);
+    let client = build_client(&api_url);
+    wait_for_server(&client).await.unwrap();
+
+    let options = DagImport {
+        stats: Some(true),
+        ..Default::default()
+    };
+    let imported: Vec<_> = client
+        .dag_import_with_options(Cursor::new(HELLO_WORLD_CAR), options)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let root = imported.iter().find_map(|line| line.root.as_ref()).unwrap();
+
+    assert_eq!(HELLO_WORLD_CID, root.cid.cid_string.to_string());
+    assert_eq!("", root.pin_error_msg);
+
+    let stats = imported
+        .iter()
+        .find_map(|line| line.stats.as_ref())
+        .unwrap();
+
+    assert_eq!(1, stats.block_count);
+
+    let exported = client
+        .dag_export(HELLO_WORLD_CID)
+        .map_ok(|chunk| chunk.to_vec())
+        .try_concat()
+        .await
+        .unwrap();
+
+    assert_eq!(HELLO_WORLD_CAR, &exported[..]);
+
+    container.teardown().await.unwrap();
+}